@@ -0,0 +1,126 @@
+use crate::container::Container;
+use luminos_contracts::container::Contract;
+use std::sync::{Arc, mpsc};
+use std::thread;
+
+/// Starts actors and hands back a cloneable address to them.
+///
+/// Implemented for the built-in [`ThreadRuntime`] and, behind the `actix`
+/// feature, for [`ActixRuntime`]. Other actor frameworks (xtra, kameo, ...)
+/// only need to provide this impl to be bindable through the container.
+pub trait ActorRuntime<A>: Send + Sync + 'static {
+    type Addr: Clone + Send + Sync + 'static;
+
+    fn start(&self, actor: A) -> Self::Addr;
+}
+
+pub trait Actor: Send + 'static {
+    type Message: Send + 'static;
+
+    fn handle(&mut self, message: Self::Message);
+
+    fn started(&mut self) {}
+
+    fn stopped(&mut self) {}
+}
+
+pub struct Addr<A: Actor> {
+    sender: mpsc::Sender<A::Message>,
+}
+
+impl<A: Actor> Addr<A> {
+    /// Returns `false` once the actor has stopped.
+    pub fn send(&self, message: A::Message) -> bool {
+        self.sender.send(message).is_ok()
+    }
+}
+
+impl<A: Actor> Clone for Addr<A> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+/// Runs each actor on its own thread; the actor stops when every `Addr` is dropped.
+#[derive(Default, Clone, Copy)]
+pub struct ThreadRuntime;
+
+impl<A: Actor> ActorRuntime<A> for ThreadRuntime {
+    type Addr = Addr<A>;
+
+    fn start(&self, mut actor: A) -> Addr<A> {
+        let (sender, receiver) = mpsc::channel();
+
+        thread::Builder::new()
+            .name(format!("actor:{}", std::any::type_name::<A>()))
+            .spawn(move || {
+                actor.started();
+                for message in receiver {
+                    actor.handle(message);
+                }
+                actor.stopped();
+            })
+            .expect("failed to spawn actor thread");
+
+        Addr { sender }
+    }
+}
+
+#[cfg(feature = "actix")]
+pub struct ActixRuntime {
+    arbiter: actix::ArbiterHandle,
+}
+
+#[cfg(feature = "actix")]
+impl ActixRuntime {
+    pub fn new(arbiter: actix::ArbiterHandle) -> Self {
+        Self { arbiter }
+    }
+
+    pub fn current() -> Self {
+        Self::new(actix::Arbiter::current())
+    }
+}
+
+#[cfg(feature = "actix")]
+impl<A> ActorRuntime<A> for ActixRuntime
+where
+    A: actix::Actor<Context = actix::Context<A>> + Send,
+{
+    type Addr = actix::Addr<A>;
+
+    fn start(&self, actor: A) -> actix::Addr<A> {
+        <A as actix::Actor>::start_in_arbiter(&self.arbiter, move |_| actor)
+    }
+}
+
+impl Container {
+    /// Binds the address of an actor built by `factory`; the actor is started
+    /// on `runtime` the first time its address is resolved.
+    #[track_caller]
+    pub fn bind_actor<A, R, F>(&self, runtime: R, factory: F) -> &Self
+    where
+        A: 'static,
+        R: ActorRuntime<A>,
+        F: Fn(&Container) -> A + Send + Sync + 'static,
+    {
+        self.bind::<R::Addr, _>(move |c| Arc::new(runtime.start(factory(c))));
+        self
+    }
+
+    pub fn actor<Addr>(&self) -> Addr
+    where
+        Addr: Clone + Send + Sync + 'static,
+    {
+        self.resolve_bound::<Addr>()
+            .map(|addr| (*addr).clone())
+            .unwrap_or_else(|| {
+                panic!(
+                    "Failed to resolve actor address: {:?}",
+                    std::any::type_name::<Addr>()
+                )
+            })
+    }
+}
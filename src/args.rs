@@ -0,0 +1,86 @@
+use crate::container::Container;
+use luminos_contracts::container::Injectable;
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Runtime construction parameters handed to factories by
+/// [`Container::resolve_with_args`].
+#[derive(Default)]
+pub struct Args {
+    values: HashMap<String, Box<dyn Any + Send + Sync>>,
+}
+
+impl Args {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with<V>(mut self, key: impl Into<String>, value: V) -> Self
+    where
+        V: Send + Sync + 'static,
+    {
+        self.values.insert(key.into(), Box::new(value));
+        self
+    }
+
+    pub fn get<V: 'static>(&self, key: &str) -> Option<&V> {
+        self.values.get(key)?.downcast_ref::<V>()
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.values.contains_key(key)
+    }
+}
+
+thread_local! {
+    static ARGS: RefCell<Vec<Option<Arc<Args>>>> = const { RefCell::new(Vec::new()) };
+}
+
+pub(crate) struct ArgsGuard;
+
+impl ArgsGuard {
+    fn push(args: Option<Arc<Args>>) -> Self {
+        ARGS.with(|stack| stack.borrow_mut().push(args));
+        Self
+    }
+
+    /// Hides the enclosing call's arguments until dropped, so a shared
+    /// instance built meanwhile cannot capture them.
+    pub(crate) fn hide() -> Self {
+        Self::push(None)
+    }
+}
+
+impl Drop for ArgsGuard {
+    fn drop(&mut self) {
+        ARGS.with(|stack| stack.borrow_mut().pop());
+    }
+}
+
+impl Container {
+    /// Builds a fresh, uncached `T` whose factory can read `args` through
+    /// [`Container::arg`]. Transient dependencies resolved by that factory
+    /// see the same arguments; singleton and scoped ones are built without
+    /// them, since they outlive this call.
+    pub fn resolve_with_args<T>(&self, args: Args) -> Arc<T>
+    where
+        T: Injectable + Send + Sync + 'static,
+    {
+        let _guard = ArgsGuard::push(Some(Arc::new(args)));
+        self.build_uncached::<T>()
+    }
+
+    /// Arguments of the innermost `resolve_with_args` call on this thread.
+    pub fn args(&self) -> Option<Arc<Args>> {
+        ARGS.with(|stack| stack.borrow().last().cloned().flatten())
+    }
+
+    pub fn arg<V>(&self, key: &str) -> Option<V>
+    where
+        V: Clone + 'static,
+    {
+        self.args()?.get::<V>(key).cloned()
+    }
+}
@@ -0,0 +1,53 @@
+use crate::container::Container;
+use luminos_contracts::container::Contract;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// A provider whose lifecycle can await, e.g. to run migrations or warm
+/// caches during boot. Implementations return boxed futures:
+///
+/// ```ignore
+/// fn boot<'a>(&'a self, container: &'a Container) -> BoxFuture<'a, ()> {
+///     Box::pin(async move { container.resolve::<Db>().migrate().await })
+/// }
+/// ```
+pub trait AsyncServiceProvider: Send + Sync {
+    fn register<'a>(&'a self, container: &'a Container) -> BoxFuture<'a, ()>;
+
+    fn boot<'a>(&'a self, container: &'a Container) -> BoxFuture<'a, ()> {
+        let _ = container;
+        Box::pin(async {})
+    }
+}
+
+impl Container {
+    pub fn add_async_provider(&self, provider: impl AsyncServiceProvider + 'static) -> &Self {
+        self.async_providers
+            .lock()
+            .unwrap()
+            .push(Arc::new(provider));
+        self
+    }
+
+    /// Registers async providers, runs the regular [`Contract::boot`], then
+    /// boots async providers, each awaited in the order they were added.
+    /// Sync providers can therefore rely on async bindings while booting.
+    pub async fn boot_async(&self) -> &Self {
+        let providers = self.async_providers.lock().unwrap().clone();
+
+        for provider in &providers {
+            provider.register(self).await;
+        }
+
+        self.boot();
+
+        for provider in &providers {
+            provider.boot(self).await;
+        }
+
+        self
+    }
+}
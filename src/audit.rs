@@ -0,0 +1,42 @@
+use crate::container::Container;
+use std::time::Duration;
+
+/// Wiring that looks dead: explicit bindings nobody resolved, and types that
+/// only exist in the container because `#[injectable]` registered them on demand.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BindingAudit {
+    pub unused: Vec<&'static str>,
+    pub implicit: Vec<&'static str>,
+}
+
+impl BindingAudit {
+    pub fn is_clean(&self) -> bool {
+        self.unused.is_empty() && self.implicit.is_empty()
+    }
+}
+
+impl Container {
+    pub fn audit_bindings(&self) -> BindingAudit {
+        let factories = self.factories.lock().unwrap();
+        let resolutions = self.resolutions.snapshot();
+        let mut audit = BindingAudit::default();
+
+        for (type_id, binding) in factories.iter() {
+            if binding.implicit {
+                audit.implicit.push(binding.type_name);
+            } else if !resolutions.contains_key(type_id) {
+                audit.unused.push(binding.type_name);
+            }
+        }
+
+        audit.unused.sort_unstable();
+        audit.implicit.sort_unstable();
+        audit
+    }
+
+    /// Like [`Container::audit_bindings`], but only once the container has
+    /// been alive for `warm_period`, so lazily used services aren't reported.
+    pub fn audit_bindings_after(&self, warm_period: Duration) -> Option<BindingAudit> {
+        (self.created_at.elapsed() >= warm_period).then(|| self.audit_bindings())
+    }
+}
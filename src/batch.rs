@@ -0,0 +1,86 @@
+use crate::container::Container;
+use crate::store::Instance;
+use luminos_contracts::container::{Contract, Injectable};
+use std::any::TypeId;
+use std::sync::Arc;
+
+/// A tuple of services resolved together by [`Container::resolve_batch`].
+pub trait Batch {
+    type Output;
+
+    fn type_ids() -> Vec<TypeId>;
+
+    /// `cached` holds one store lookup per element, in tuple order.
+    fn resolve_from(container: &Container, cached: Vec<Option<Instance>>) -> Self::Output;
+}
+
+fn resolve_one<T>(container: &Container, cached: Option<Instance>) -> Arc<T>
+where
+    T: Injectable + Send + Sync + 'static,
+{
+    match cached.and_then(|inst| inst.downcast::<T>().ok()) {
+        Some(inst) => {
+            container.record_dependency(std::any::type_name::<T>());
+            container.record_resolution(TypeId::of::<T>());
+            inst
+        }
+        None => container.resolve::<T>(),
+    }
+}
+
+macro_rules! impl_batch {
+    ($($ty:ident),+) => {
+        impl<$($ty),+> Batch for ($($ty,)+)
+        where
+            $($ty: Injectable + Send + Sync + 'static,)+
+        {
+            type Output = ($(Arc<$ty>,)+);
+
+            fn type_ids() -> Vec<TypeId> {
+                vec![$(TypeId::of::<$ty>()),+]
+            }
+
+            fn resolve_from(container: &Container, cached: Vec<Option<Instance>>) -> Self::Output {
+                let mut cached = cached.into_iter();
+                ($(resolve_one::<$ty>(container, cached.next().flatten()),)+)
+            }
+        }
+    };
+}
+
+impl_batch!(A1);
+impl_batch!(A1, A2);
+impl_batch!(A1, A2, A3);
+impl_batch!(A1, A2, A3, A4);
+impl_batch!(A1, A2, A3, A4, A5);
+impl_batch!(A1, A2, A3, A4, A5, A6);
+impl_batch!(A1, A2, A3, A4, A5, A6, A7);
+impl_batch!(A1, A2, A3, A4, A5, A6, A7, A8);
+
+impl Container {
+    /// Resolves a tuple of services, reading already-built ones from the
+    /// instance store under a single lock. Anything missing falls back to
+    /// a regular `resolve`, as does everything while a scope, contextual
+    /// binding or global resolved callback is in play.
+    pub fn resolve_batch<B: Batch>(&self) -> B::Output {
+        let type_ids = B::type_ids();
+        let cached = if self.cache_is_authoritative() {
+            self.instances.get_many(&type_ids)
+        } else {
+            vec![None; type_ids.len()]
+        };
+        B::resolve_from(self, cached)
+    }
+}
+
+/// Binds several services in one statement:
+///
+/// ```ignore
+/// resolve_batch!((users: UserRepo, mailer: Mailer) from container);
+/// ```
+#[macro_export]
+macro_rules! resolve_batch {
+    (($($name:ident : $ty:ty),+ $(,)?) from $container:expr) => {
+        let ($($name,)+) = $container.resolve_batch::<($($ty,)+)>();
+    };
+}
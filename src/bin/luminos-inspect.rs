@@ -0,0 +1,28 @@
+use luminos_container::Diagnostics;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let Some(path) = std::env::args().nth(1) else {
+        eprintln!("usage: luminos-inspect <diagnostics-export>");
+        return ExitCode::from(2);
+    };
+
+    let input = match std::fs::read_to_string(&path) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("luminos-inspect: cannot read {path}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match Diagnostics::parse(&input) {
+        Ok(diagnostics) => {
+            print!("{diagnostics}");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("luminos-inspect: {path}: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
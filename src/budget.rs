@@ -0,0 +1,136 @@
+use crate::container::{Container, ProviderEntry};
+use crate::profile::ProfileEntry;
+use std::fmt;
+use std::panic::Location;
+use std::time::{Duration, Instant};
+
+const REPORTED_ENTRIES: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProviderTiming {
+    pub name: Option<&'static str>,
+    pub location: &'static Location<'static>,
+    pub duration: Duration,
+}
+
+/// Produced when boot or prewarm takes longer than the configured budget.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BudgetReport {
+    pub budget: Duration,
+    pub elapsed: Duration,
+    /// Slowest factories first.
+    pub factories: Vec<ProfileEntry>,
+    /// Slowest providers first, `register` and `boot` combined.
+    pub providers: Vec<ProviderTiming>,
+}
+
+impl fmt::Display for BudgetReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "startup took {:?}, over the {:?} budget",
+            self.elapsed, self.budget
+        )?;
+        if !self.providers.is_empty() {
+            writeln!(f, "slowest providers:")?;
+            for provider in &self.providers {
+                writeln!(
+                    f,
+                    "  {:>10?}  {} ({})",
+                    provider.duration,
+                    provider.name.unwrap_or("<unnamed>"),
+                    provider.location
+                )?;
+            }
+        }
+        if !self.factories.is_empty() {
+            writeln!(f, "slowest factories:")?;
+            for entry in &self.factories {
+                writeln!(f, "  {:>10?}  {}", entry.duration, entry.type_name)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A budgeted boot or prewarm in progress.
+pub(crate) struct BudgetRun {
+    budget: Duration,
+    started: Instant,
+    owns_profiling: bool,
+}
+
+impl Container {
+    /// Maximum wall time for `boot` and `prewarm`. Overruns are kept for
+    /// `budget_report` and, with the `tracing` feature, emitted as a warning.
+    pub fn set_budget(&self, budget: Duration) -> &Self {
+        *self.budget.lock().unwrap() = Some(budget);
+        self
+    }
+
+    /// Report from the most recent run that exceeded the budget.
+    pub fn budget_report(&self) -> Option<BudgetReport> {
+        self.budget_report.lock().unwrap().clone()
+    }
+
+    pub(crate) fn start_budget(&self) -> Option<BudgetRun> {
+        let budget = (*self.budget.lock().unwrap())?;
+        let mut profiling = self.profiling.lock().unwrap();
+        let owns_profiling = profiling.is_none();
+        if owns_profiling {
+            *profiling = Some(Vec::new());
+        }
+
+        Some(BudgetRun {
+            budget,
+            started: Instant::now(),
+            owns_profiling,
+        })
+    }
+
+    pub(crate) fn finish_budget(&self, run: BudgetRun, providers: &[ProviderEntry]) {
+        let elapsed = run.started.elapsed();
+        let mut factories = {
+            let mut profiling = self.profiling.lock().unwrap();
+            if run.owns_profiling {
+                profiling.take().unwrap_or_default()
+            } else {
+                profiling.clone().unwrap_or_default()
+            }
+        };
+
+        if elapsed <= run.budget {
+            return;
+        }
+
+        factories.sort_by_key(|entry| std::cmp::Reverse(entry.duration));
+        factories.truncate(REPORTED_ENTRIES);
+
+        let mut providers: Vec<ProviderTiming> = providers
+            .iter()
+            .map(|entry| ProviderTiming {
+                name: entry.name,
+                location: entry.location,
+                duration: entry.elapsed,
+            })
+            .collect();
+        providers.sort_by_key(|timing| std::cmp::Reverse(timing.duration));
+        providers.truncate(REPORTED_ENTRIES);
+
+        let report = BudgetReport {
+            budget: run.budget,
+            elapsed,
+            factories,
+            providers,
+        };
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            target: "luminos_container",
+            elapsed_ms = report.elapsed.as_millis() as u64,
+            budget_ms = report.budget.as_millis() as u64,
+            report = %report,
+            "startup exceeded its budget"
+        );
+        *self.budget_report.lock().unwrap() = Some(report);
+    }
+}
@@ -0,0 +1,71 @@
+use crate::container::Container;
+use luminos_contracts::container::Contract;
+use std::any::{Any, TypeId};
+use std::sync::Arc;
+
+/// A service assembled from a builder that several providers may configure.
+pub trait Buildable: Send + Sync + Sized + 'static {
+    type Builder: Default + 'static;
+
+    fn build(builder: Self::Builder, container: &Container) -> Self;
+}
+
+type Configurer<B> = Arc<dyn Fn(B, &Container) -> B + Send + Sync>;
+
+impl Container {
+    /// Queues a configuration step for builder `B`; steps run in registration order.
+    pub fn configure<B, F>(&self, configurer: F) -> &Self
+    where
+        B: 'static,
+        F: Fn(B, &Container) -> B + Send + Sync + 'static,
+    {
+        let configurer: Configurer<B> = Arc::new(configurer);
+        self.configurers
+            .lock()
+            .unwrap()
+            .entry(TypeId::of::<B>())
+            .or_default()
+            .push(Arc::new(configurer));
+        self
+    }
+
+    /// Runs every queued configurer against a default `T::Builder` and builds `T`.
+    pub fn build_configured<T: Buildable>(&self) -> T {
+        let configurers: Vec<Arc<dyn Any + Send + Sync>> = self
+            .configurers
+            .lock()
+            .unwrap()
+            .get(&TypeId::of::<T::Builder>())
+            .cloned()
+            .unwrap_or_default();
+
+        let builder = configurers
+            .iter()
+            .fold(T::Builder::default(), |builder, c| {
+                let configurer = c.downcast_ref::<Configurer<T::Builder>>().unwrap();
+                configurer(builder, self)
+            });
+
+        T::build(builder, self)
+    }
+
+    /// Binds `T` as a singleton produced by [`Container::build_configured`].
+    #[track_caller]
+    pub fn bind_configured<T: Buildable>(&self) -> &Self {
+        self.bind::<T, _>(|c| Arc::new(c.build_configured::<T>()));
+        self
+    }
+
+    pub fn resolve_configured<T: Buildable>(&self) -> Arc<T> {
+        if !self
+            .factories
+            .lock()
+            .unwrap()
+            .contains_key(&TypeId::of::<T>())
+        {
+            self.bind_configured::<T>();
+        }
+
+        self.resolve_bound::<T>().unwrap()
+    }
+}
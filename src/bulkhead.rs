@@ -0,0 +1,90 @@
+use crate::container::Container;
+use std::any::TypeId;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkheadMode {
+    /// Block until a build slot frees up.
+    Wait,
+    /// Block for at most the given duration, then fail.
+    WaitFor(Duration),
+    /// Fail immediately when every slot is taken.
+    FailFast,
+}
+
+/// Counting semaphore limiting how many factories for one type run at once.
+pub(crate) struct Bulkhead {
+    limit: usize,
+    mode: BulkheadMode,
+    running: Mutex<usize>,
+    released: Condvar,
+}
+
+pub(crate) struct BulkheadPermit<'a> {
+    bulkhead: &'a Bulkhead,
+}
+
+impl Bulkhead {
+    fn new(limit: usize, mode: BulkheadMode) -> Self {
+        Self {
+            limit: limit.max(1),
+            mode,
+            running: Mutex::new(0),
+            released: Condvar::new(),
+        }
+    }
+
+    pub(crate) fn acquire(&self) -> Option<BulkheadPermit<'_>> {
+        let mut running = self.running.lock().unwrap();
+
+        match self.mode {
+            BulkheadMode::Wait => {
+                running = self
+                    .released
+                    .wait_while(running, |n| *n >= self.limit)
+                    .unwrap();
+            }
+            BulkheadMode::WaitFor(timeout) => {
+                let (guard, result) = self
+                    .released
+                    .wait_timeout_while(running, timeout, |n| *n >= self.limit)
+                    .unwrap();
+                if result.timed_out() {
+                    return None;
+                }
+                running = guard;
+            }
+            BulkheadMode::FailFast => {
+                if *running >= self.limit {
+                    return None;
+                }
+            }
+        }
+
+        *running += 1;
+        Some(BulkheadPermit { bulkhead: self })
+    }
+}
+
+impl Drop for BulkheadPermit<'_> {
+    fn drop(&mut self) {
+        *self.bulkhead.running.lock().unwrap() -= 1;
+        self.bulkhead.released.notify_one();
+    }
+}
+
+impl Container {
+    /// Caps concurrent factory runs for `T` at `limit`; resolutions beyond
+    /// that wait or fail according to `mode`.
+    pub fn max_concurrent_builds<T>(&self, limit: usize, mode: BulkheadMode) -> &Self
+    where
+        T: Send + Sync + 'static,
+    {
+        self.bulkheads
+            .lock()
+            .unwrap()
+            .insert(TypeId::of::<T>(), Arc::new(Bulkhead::new(limit, mode)));
+        self
+    }
+}
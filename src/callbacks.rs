@@ -0,0 +1,89 @@
+use crate::container::Container;
+use crate::scope::Scope;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+type Callback<E> = Arc<dyn Fn(&E, &Scope<'_>) + Send + Sync>;
+
+/// Typed callbacks keyed by event type. A lighter alternative to a full
+/// event bus: callbacks receive the event and the scope it is dispatched in.
+#[derive(Default)]
+pub struct CallbackRegistry {
+    callbacks: RwLock<HashMap<TypeId, Vec<Arc<dyn Any + Send + Sync>>>>,
+}
+
+impl CallbackRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn on<E, F>(&self, callback: F) -> &Self
+    where
+        E: 'static,
+        F: Fn(&E, &Scope<'_>) + Send + Sync + 'static,
+    {
+        let callback: Callback<E> = Arc::new(callback);
+        self.callbacks
+            .write()
+            .unwrap()
+            .entry(TypeId::of::<E>())
+            .or_default()
+            .push(Arc::new(callback));
+        self
+    }
+
+    pub fn has_callbacks<E: 'static>(&self) -> bool {
+        self.callbacks
+            .read()
+            .unwrap()
+            .get(&TypeId::of::<E>())
+            .is_some_and(|callbacks| !callbacks.is_empty())
+    }
+
+    /// Runs every callback for `E` in registration order; returns how many ran.
+    pub fn dispatch<E: 'static>(&self, event: &E, scope: &Scope<'_>) -> usize {
+        let callbacks = self
+            .callbacks
+            .read()
+            .unwrap()
+            .get(&TypeId::of::<E>())
+            .cloned()
+            .unwrap_or_default();
+
+        scope.enter(|scope| {
+            for callback in &callbacks {
+                (callback.downcast_ref::<Callback<E>>().unwrap())(event, scope);
+            }
+        });
+
+        callbacks.len()
+    }
+}
+
+impl Container {
+    pub fn callbacks(&self) -> Arc<CallbackRegistry> {
+        self.internal_or_insert_with(CallbackRegistry::new)
+    }
+
+    pub fn on<E, F>(&self, callback: F) -> &Self
+    where
+        E: 'static,
+        F: Fn(&E, &Scope<'_>) + Send + Sync + 'static,
+    {
+        self.callbacks().on::<E, F>(callback);
+        self
+    }
+
+    /// Dispatches `event` inside a fresh scope that is disposed afterwards.
+    pub fn dispatch<E: 'static>(&self, event: &E) -> usize {
+        let scope = self.scope();
+        self.callbacks().dispatch(event, &scope)
+    }
+}
+
+impl Scope<'_> {
+    pub fn dispatch<E: 'static>(&self, event: &E) -> usize {
+        self.container().callbacks().dispatch(event, self)
+    }
+}
@@ -0,0 +1,140 @@
+use luminos_contracts::container::{Contract, Injectable};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+/// Point in time after which scoped work should give up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Deadline(Instant);
+
+impl Deadline {
+    pub fn at(instant: Instant) -> Self {
+        Self(instant)
+    }
+
+    pub fn after(duration: Duration) -> Self {
+        Self(Instant::now() + duration)
+    }
+
+    pub fn instant(&self) -> Instant {
+        self.0
+    }
+
+    pub fn remaining(&self) -> Duration {
+        self.0.saturating_duration_since(Instant::now())
+    }
+
+    pub fn has_passed(&self) -> bool {
+        Instant::now() >= self.0
+    }
+}
+
+#[derive(Default)]
+struct TokenState {
+    cancelled: AtomicBool,
+    lock: Mutex<()>,
+    signal: Condvar,
+    parent: Option<CancellationToken>,
+    deadline: Option<Deadline>,
+}
+
+/// Cooperative cancellation flag shared between a scope and the services
+/// resolved inside it. Cheap to clone; all clones observe the same state.
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    state: Arc<TokenState>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_deadline(deadline: Deadline) -> Self {
+        Self {
+            state: Arc::new(TokenState {
+                deadline: Some(deadline),
+                ..TokenState::default()
+            }),
+        }
+    }
+
+    /// A token cancelled whenever `self` is, but which can also be cancelled on its own.
+    pub fn child(&self) -> Self {
+        Self {
+            state: Arc::new(TokenState {
+                parent: Some(self.clone()),
+                ..TokenState::default()
+            }),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.state.cancelled.store(true, Ordering::SeqCst);
+        let _guard = self.state.lock.lock().unwrap();
+        self.state.signal.notify_all();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.state.cancelled.load(Ordering::SeqCst)
+            || self.state.deadline.is_some_and(|d| d.has_passed())
+            || self
+                .state
+                .parent
+                .as_ref()
+                .is_some_and(|parent| parent.is_cancelled())
+    }
+
+    pub fn deadline(&self) -> Option<Deadline> {
+        let parent = self.state.parent.as_ref().and_then(|p| p.deadline());
+
+        match (self.state.deadline, parent) {
+            (Some(own), Some(parent)) => Some(own.min(parent)),
+            (own, parent) => own.or(parent),
+        }
+    }
+
+    /// Blocks until cancelled or `timeout` elapses; returns whether the token was cancelled.
+    ///
+    /// Parent cancellation is observed by polling, so waiting on a child
+    /// may lag the parent by up to a few milliseconds.
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        let until = Instant::now() + timeout;
+        let mut guard = self.state.lock.lock().unwrap();
+
+        loop {
+            if self.is_cancelled() {
+                return true;
+            }
+
+            let now = Instant::now();
+            if now >= until {
+                return false;
+            }
+
+            let mut wait = until - now;
+            if let Some(deadline) = self.deadline() {
+                wait = wait.min(deadline.remaining());
+            }
+            if self.state.parent.is_some() {
+                wait = wait.min(Duration::from_millis(5));
+            }
+
+            guard = self.state.signal.wait_timeout(guard, wait).unwrap().0;
+        }
+    }
+}
+
+// Inside a scope both resolve to the scope's own values, which shadow these
+// registrations. Outside one, and for singletons, which are built with the
+// scope hidden, there is nothing to cancel: the token never fires and there
+// is no deadline to give out.
+impl Injectable for CancellationToken {
+    fn __register<C: Contract>(container: &C) {
+        container.bind::<CancellationToken, _>(|_| Arc::new(CancellationToken::new()));
+    }
+}
+
+impl Injectable for Deadline {
+    fn __register<C: Contract>(_container: &C) {}
+}
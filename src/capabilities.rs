@@ -0,0 +1,117 @@
+use crate::container::{Container, ProviderEntry};
+use std::any::TypeId;
+use std::collections::{BTreeSet, HashSet};
+use std::sync::Arc;
+
+/// Cargo features this crate was compiled with.
+const FEATURES: &[(&str, bool)] = &[
+    ("actix", cfg!(feature = "actix")),
+    ("actors", cfg!(feature = "actors")),
+    ("chaos", cfg!(feature = "chaos")),
+    ("config", cfg!(feature = "config")),
+    ("config-rs", cfg!(feature = "config-rs")),
+    ("figment", cfg!(feature = "figment")),
+    ("inspect", cfg!(feature = "inspect")),
+    ("metrics", cfg!(feature = "metrics")),
+    ("remote", cfg!(feature = "remote")),
+    ("serde", cfg!(feature = "serde")),
+    ("testing", cfg!(feature = "testing")),
+    ("tracing", cfg!(feature = "tracing")),
+    ("vault", cfg!(feature = "vault")),
+];
+
+/// Which optional subsystems are wired into a container, so services can
+/// branch on a missing cache or queue instead of attempting a resolve.
+/// Refreshed at every boot.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Capabilities {
+    features: BTreeSet<&'static str>,
+    providers: BTreeSet<&'static str>,
+    declared: BTreeSet<String>,
+    bindings: HashSet<TypeId>,
+}
+
+impl Capabilities {
+    /// True for a declared capability, a compiled-in feature or a named provider.
+    pub fn has(&self, name: &str) -> bool {
+        self.declared.contains(name)
+            || self.features.contains(name)
+            || self.providers.contains(name)
+    }
+
+    pub fn has_feature(&self, feature: &str) -> bool {
+        self.features.contains(feature)
+    }
+
+    pub fn has_provider(&self, name: &str) -> bool {
+        self.providers.contains(name)
+    }
+
+    pub fn has_binding<T: ?Sized + 'static>(&self) -> bool {
+        self.bindings.contains(&TypeId::of::<T>())
+    }
+
+    pub fn has_cache(&self) -> bool {
+        self.has("cache")
+    }
+
+    pub fn has_queue(&self) -> bool {
+        self.has("queue")
+    }
+
+    /// Every name `has` answers true for, sorted.
+    pub fn names(&self) -> Vec<String> {
+        let mut names: BTreeSet<String> = self.declared.clone();
+        names.extend(self.features.iter().map(|s| s.to_string()));
+        names.extend(self.providers.iter().map(|s| s.to_string()));
+        names.into_iter().collect()
+    }
+}
+
+impl Container {
+    /// Records that an optional subsystem is available, typically from the
+    /// `register` of the provider wiring it.
+    pub fn declare_capability(&self, name: &str) -> &Self {
+        self.declared_capabilities
+            .lock()
+            .unwrap()
+            .insert(name.to_string());
+        self.remove_internal::<Capabilities>();
+        self
+    }
+
+    /// Capabilities as of the last boot, or computed now before the first one.
+    pub fn capabilities(&self) -> Arc<Capabilities> {
+        if let Some(capabilities) = self.internal::<Capabilities>() {
+            return capabilities;
+        }
+
+        let providers = self
+            .providers
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|entry| entry.name)
+            .collect();
+        self.store_capabilities(providers)
+    }
+
+    pub(crate) fn refresh_capabilities(&self, providers: &[ProviderEntry]) {
+        self.store_capabilities(providers.iter().filter_map(|entry| entry.name).collect());
+    }
+
+    fn store_capabilities(&self, providers: BTreeSet<&'static str>) -> Arc<Capabilities> {
+        let capabilities = Arc::new(Capabilities {
+            features: FEATURES
+                .iter()
+                .filter(|(_, enabled)| *enabled)
+                .map(|(name, _)| *name)
+                .collect(),
+            providers,
+            declared: self.declared_capabilities.lock().unwrap().clone(),
+            bindings: self.factories.lock().unwrap().keys().copied().collect(),
+        });
+        self.set_internal(capabilities.clone());
+        capabilities
+    }
+}
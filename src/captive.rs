@@ -0,0 +1,130 @@
+use crate::container::Container;
+use crate::error::panic_message;
+use crate::lifetime::Lifetime;
+use std::collections::HashMap;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+/// A singleton that depends on a shorter-lived binding and would therefore
+/// freeze one instance of it for the life of the container.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaptiveDependency {
+    pub singleton: &'static str,
+    pub captured: &'static str,
+    pub lifetime: Lifetime,
+}
+
+impl fmt::Display for CaptiveDependency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "singleton {} captures {} dependency {}",
+            self.singleton, self.lifetime, self.captured
+        )
+    }
+}
+
+/// What [`Container::verify`] found wrong with the singleton graph.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VerifyReport {
+    pub captive: Vec<CaptiveDependency>,
+    /// Singletons whose construction failed for another reason, with the reason.
+    pub unresolvable: Vec<(&'static str, String)>,
+}
+
+impl fmt::Display for VerifyReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for captive in &self.captive {
+            writeln!(f, "{captive}")?;
+        }
+        for (type_name, message) in &self.unresolvable {
+            writeln!(f, "cannot build {type_name}: {message}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for VerifyReport {}
+
+impl Container {
+    /// Captive dependencies among the edges declared or observed so far.
+    pub fn captive_dependencies(&self) -> Vec<CaptiveDependency> {
+        let lifetimes: HashMap<&'static str, Lifetime> = self
+            .factories
+            .lock()
+            .unwrap()
+            .values()
+            .map(|binding| (binding.type_name, binding.lifetime))
+            .collect();
+
+        let mut captive: Vec<CaptiveDependency> = self
+            .dependencies
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(consumer, _)| lifetimes.get(*consumer) == Some(&Lifetime::Singleton))
+            .flat_map(|(consumer, dependencies)| {
+                dependencies.iter().filter_map(|dependency| {
+                    let lifetime = *lifetimes.get(dependency)?;
+                    (lifetime != Lifetime::Singleton).then_some(CaptiveDependency {
+                        singleton: consumer,
+                        captured: dependency,
+                        lifetime,
+                    })
+                })
+            })
+            .collect();
+
+        captive.sort_by_key(|c| (c.singleton, c.captured));
+        captive
+    }
+
+    /// Builds every explicitly bound singleton in a staging copy, so their
+    /// dependency edges are observed without caching anything here, then
+    /// checks for captive dependencies. The copy shares this container's
+    /// internals and deferred providers, so factories see the same
+    /// environment they would at runtime.
+    ///
+    /// Singletons are built without access to any scope, so one that needs
+    /// a scoped service fails to build; the edge is recorded before that and
+    /// reported as captive. Any other failure is reported as unresolvable.
+    pub fn verify(&self) -> Result<(), VerifyReport> {
+        let singletons: Vec<_> = self
+            .factories
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, binding)| !binding.implicit && binding.lifetime == Lifetime::Singleton)
+            .map(|(type_id, binding)| (*type_id, binding.type_name))
+            .collect();
+
+        let staging = self.staging_copy();
+        staging.audit_scope_escapes(false);
+        *staging.dependencies.lock().unwrap() = self.dependencies.lock().unwrap().clone();
+
+        let mut failed = Vec::new();
+        for (type_id, type_name) in singletons {
+            if let Err(payload) =
+                panic::catch_unwind(AssertUnwindSafe(|| staging.resolve_instance(type_id)))
+            {
+                failed.push((type_name, panic_message(&*payload)));
+            }
+        }
+
+        let captive = staging.captive_dependencies();
+        let mut unresolvable: Vec<_> = failed
+            .into_iter()
+            .filter(|(type_name, _)| !captive.iter().any(|c| c.singleton == *type_name))
+            .collect();
+        unresolvable.sort();
+
+        if captive.is_empty() && unresolvable.is_empty() {
+            Ok(())
+        } else {
+            Err(VerifyReport {
+                captive,
+                unresolvable,
+            })
+        }
+    }
+}
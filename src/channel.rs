@@ -0,0 +1,168 @@
+use crate::container::Container;
+use luminos_contracts::container::{Contract, Injectable};
+use std::sync::mpsc::{self, RecvError, RecvTimeoutError, SyncSender, TryRecvError, TrySendError};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+struct Hub<T> {
+    capacity: usize,
+    subscribers: Mutex<Vec<Arc<SyncSender<T>>>>,
+}
+
+/// Producer half of a channel bound with [`Container::bind_channel`]. Every
+/// message is cloned to each live [`Receiver`].
+pub struct Sender<T> {
+    hub: Arc<Hub<T>>,
+}
+
+impl<T: Clone> Sender<T> {
+    /// Blocks while any subscriber's buffer is full. Returns how many
+    /// receivers got the message; `Err` hands it back when there are none.
+    pub fn send(&self, message: T) -> Result<usize, T> {
+        // Blocking sends happen outside the lock, so a full buffer never
+        // stalls `subscribe` or other senders.
+        let subscribers = self.hub.subscribers.lock().unwrap().clone();
+        let mut delivered = 0;
+        let mut disconnected = Vec::new();
+        for tx in subscribers {
+            match tx.send(message.clone()) {
+                Ok(()) => delivered += 1,
+                Err(_) => disconnected.push(tx),
+            }
+        }
+
+        if !disconnected.is_empty() {
+            self.hub
+                .subscribers
+                .lock()
+                .unwrap()
+                .retain(|tx| !disconnected.iter().any(|dead| Arc::ptr_eq(dead, tx)));
+        }
+
+        if delivered == 0 {
+            Err(message)
+        } else {
+            Ok(delivered)
+        }
+    }
+
+    /// Like `send`, but skips subscribers whose buffer is full instead of waiting.
+    pub fn try_send(&self, message: T) -> Result<usize, T> {
+        let mut delivered = 0;
+        self.hub
+            .subscribers
+            .lock()
+            .unwrap()
+            .retain(|tx| match tx.try_send(message.clone()) {
+                Ok(()) => {
+                    delivered += 1;
+                    true
+                }
+                Err(TrySendError::Full(_)) => true,
+                Err(TrySendError::Disconnected(_)) => false,
+            });
+
+        if delivered == 0 {
+            Err(message)
+        } else {
+            Ok(delivered)
+        }
+    }
+}
+
+impl<T> Sender<T> {
+    pub fn receiver_count(&self) -> usize {
+        self.hub.subscribers.lock().unwrap().len()
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            hub: self.hub.clone(),
+        }
+    }
+}
+
+/// Consumer half; only sees messages sent after it subscribed.
+pub struct Receiver<T> {
+    inner: mpsc::Receiver<T>,
+}
+
+impl<T> Receiver<T> {
+    pub fn recv(&self) -> Result<T, RecvError> {
+        self.inner.recv()
+    }
+
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        self.inner.try_recv()
+    }
+
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+        self.inner.recv_timeout(timeout)
+    }
+
+    pub fn iter(&self) -> mpsc::Iter<'_, T> {
+        self.inner.iter()
+    }
+}
+
+/// Injected by consumers to open their own [`Receiver`].
+pub struct Receivers<T> {
+    hub: Arc<Hub<T>>,
+}
+
+impl<T> Receivers<T> {
+    pub fn subscribe(&self) -> Receiver<T> {
+        let (tx, rx) = mpsc::sync_channel(self.hub.capacity);
+        self.hub.subscribers.lock().unwrap().push(Arc::new(tx));
+        Receiver { inner: rx }
+    }
+}
+
+// Both halves are bound by `bind_channel`; there is nothing to auto-register.
+impl<T> Injectable for Sender<T> {
+    fn __register<C: Contract>(_container: &C) {}
+}
+
+impl<T> Injectable for Receivers<T> {
+    fn __register<C: Contract>(_container: &C) {}
+}
+
+impl Container {
+    /// Binds `Sender<T>` and `Receivers<T>` sharing one broadcast hub. Each
+    /// receiver buffers up to `capacity` messages before senders block.
+    #[track_caller]
+    pub fn bind_channel<T>(&self, capacity: usize) -> &Self
+    where
+        T: Clone + Send + 'static,
+    {
+        let hub = Arc::new(Hub {
+            capacity,
+            subscribers: Mutex::new(Vec::new()),
+        });
+
+        let senders = hub.clone();
+        self.bind::<Sender<T>, _>(move |_| {
+            Arc::new(Sender {
+                hub: senders.clone(),
+            })
+        });
+        self.bind::<Receivers<T>, _>(move |_| Arc::new(Receivers { hub: hub.clone() }));
+        self
+    }
+
+    pub fn sender<T>(&self) -> Arc<Sender<T>>
+    where
+        T: Clone + Send + 'static,
+    {
+        self.resolve::<Sender<T>>()
+    }
+
+    pub fn subscribe<T>(&self) -> Receiver<T>
+    where
+        T: Clone + Send + 'static,
+    {
+        self.resolve::<Receivers<T>>().subscribe()
+    }
+}
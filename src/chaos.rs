@@ -0,0 +1,185 @@
+use crate::container::Container;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ChaosRule {
+    /// Probability in `0.0..=1.0` that an operation fails.
+    pub failure_rate: f64,
+    /// Probability in `0.0..=1.0` that an operation is delayed by `delay`.
+    pub delay_rate: f64,
+    pub delay: Duration,
+}
+
+impl ChaosRule {
+    pub fn failing(failure_rate: f64) -> Self {
+        Self {
+            failure_rate,
+            ..Self::default()
+        }
+    }
+
+    pub fn delaying(delay_rate: f64, delay: Duration) -> Self {
+        Self {
+            delay_rate,
+            delay,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChaosFault {
+    pub service: &'static str,
+}
+
+impl fmt::Display for ChaosFault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "chaos: injected failure in {}", self.service)
+    }
+}
+
+impl std::error::Error for ChaosFault {}
+
+/// Runtime switchboard for fault injection, shared through the container.
+pub struct ChaosConfig {
+    enabled: AtomicBool,
+    rules: RwLock<HashMap<TypeId, ChaosRule>>,
+    state: AtomicU64,
+}
+
+impl ChaosConfig {
+    pub fn new() -> Self {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64);
+        Self::with_seed(seed)
+    }
+
+    /// A config whose fault sequence is reproducible across runs.
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            enabled: AtomicBool::new(false),
+            rules: RwLock::new(HashMap::new()),
+            state: AtomicU64::new(seed | 1),
+        }
+    }
+
+    pub fn enable(&self) {
+        self.enabled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn disable(&self) {
+        self.enabled.store(false, Ordering::SeqCst);
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::SeqCst)
+    }
+
+    pub fn set_rule<T: ?Sized + 'static>(&self, rule: ChaosRule) {
+        self.rules.write().unwrap().insert(TypeId::of::<T>(), rule);
+    }
+
+    pub fn clear_rule<T: ?Sized + 'static>(&self) {
+        self.rules.write().unwrap().remove(&TypeId::of::<T>());
+    }
+
+    /// Applies the rule for `type_id`: may sleep, then may report a fault.
+    pub(crate) fn check(&self, type_id: TypeId, service: &'static str) -> Result<(), ChaosFault> {
+        if !self.is_enabled() {
+            return Ok(());
+        }
+        let Some(rule) = self.rules.read().unwrap().get(&type_id).copied() else {
+            return Ok(());
+        };
+
+        if rule.delay_rate > 0.0 && self.roll() < rule.delay_rate {
+            std::thread::sleep(rule.delay);
+        }
+        if rule.failure_rate > 0.0 && self.roll() < rule.failure_rate {
+            return Err(ChaosFault { service });
+        }
+
+        Ok(())
+    }
+
+    /// xorshift64*, mapped to `0.0..1.0`.
+    fn roll(&self) -> f64 {
+        let mut x = self.state.load(Ordering::Relaxed);
+        loop {
+            let mut next = x;
+            next ^= next >> 12;
+            next ^= next << 25;
+            next ^= next >> 27;
+            match self
+                .state
+                .compare_exchange_weak(x, next, Ordering::Relaxed, Ordering::Relaxed)
+            {
+                Ok(_) => {
+                    let value = next.wrapping_mul(0x2545_f491_4f6c_dd1d);
+                    return (value >> 11) as f64 / (1u64 << 53) as f64;
+                }
+                Err(current) => x = current,
+            }
+        }
+    }
+}
+
+impl Default for ChaosConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Wraps a resolved service so calls through it are subject to its chaos rule.
+pub struct Chaos<T: ?Sized> {
+    inner: Arc<T>,
+    config: Arc<ChaosConfig>,
+}
+
+impl<T: ?Sized + 'static> Chaos<T> {
+    pub fn inner(&self) -> &Arc<T> {
+        &self.inner
+    }
+
+    pub fn call<R, F>(&self, operation: F) -> Result<R, ChaosFault>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        self.config
+            .check(TypeId::of::<T>(), std::any::type_name::<T>())?;
+        Ok(operation(&self.inner))
+    }
+}
+
+impl Container {
+    /// The container's [`ChaosConfig`], created (disabled) on first use.
+    /// Rules set here also apply to construction of the matching binding.
+    pub fn chaos(&self) -> Arc<ChaosConfig> {
+        self.internal_or_insert_with(ChaosConfig::new)
+    }
+
+    pub fn with_chaos<T: ?Sized + 'static>(&self, inner: Arc<T>) -> Chaos<T> {
+        Chaos {
+            inner,
+            config: self.chaos(),
+        }
+    }
+
+    pub(crate) fn inject_construction_chaos(&self, type_id: TypeId, service: &'static str) {
+        let Some(config) = self.internal::<ChaosConfig>() else {
+            return;
+        };
+
+        if let Err(fault) = config.check(type_id, service) {
+            // A typed payload lets `try_resolve` report `FaultInjected`
+            // rather than a generic factory panic.
+            std::panic::panic_any(fault);
+        }
+    }
+}
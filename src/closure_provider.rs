@@ -0,0 +1,52 @@
+use crate::container::Container;
+use luminos_contracts::container::Contract;
+use luminos_contracts::support::ServiceProvider;
+
+type Hook = Box<dyn Fn(&Container) + Send + Sync>;
+
+/// A provider assembled from closures, for wiring too small to deserve a named type.
+pub struct ClosureProvider {
+    register: Hook,
+    boot: Hook,
+}
+
+impl ClosureProvider {
+    pub fn new<R, B>(register: R, boot: B) -> Self
+    where
+        R: Fn(&Container) + Send + Sync + 'static,
+        B: Fn(&Container) + Send + Sync + 'static,
+    {
+        Self {
+            register: Box::new(register),
+            boot: Box::new(boot),
+        }
+    }
+
+    pub fn register_only<R>(register: R) -> Self
+    where
+        R: Fn(&Container) + Send + Sync + 'static,
+    {
+        Self::new(register, |_| {})
+    }
+}
+
+impl ServiceProvider<Container> for ClosureProvider {
+    fn register(&self, container: &Container) {
+        (self.register)(container);
+    }
+
+    fn boot(&self, container: &Container) {
+        (self.boot)(container);
+    }
+}
+
+impl Container {
+    #[track_caller]
+    pub fn provide<R, B>(&self, register: R, boot: B) -> &Self
+    where
+        R: Fn(&Container) + Send + Sync + 'static,
+        B: Fn(&Container) + Send + Sync + 'static,
+    {
+        self.add_provider(Box::new(ClosureProvider::new(register, boot)))
+    }
+}
@@ -1,90 +1,947 @@
+use crate::args::ArgsGuard;
+use crate::async_provider::AsyncServiceProvider;
+use crate::budget::BudgetReport;
+use crate::bulkhead::Bulkhead;
+use crate::contextual::ContextualBindings;
+use crate::decorate::Decorators;
+use crate::deferred::DeferredEntry;
+use crate::dependents::DependencyEdges;
+use crate::error::ContainerError;
+use crate::escape::EscapeAudit;
+use crate::lifetime::Lifetime;
+use crate::local::{self, LocalFactory};
+use crate::manifest::{self, AddedManifest, ManifestConflict, ProviderManifest};
+use crate::multi::MultiBindings;
+use crate::named::NamedBindings;
+use crate::options::OptionsValidator;
+use crate::ordering;
+use crate::persistent::PersistentStates;
+use crate::profile::ProfileEntry;
+use crate::promise::BindingWaiters;
+use crate::provider_state::{ProviderStateGuard, StateSlots};
+use crate::refcount::RefcountSamples;
+use crate::resolving::ResolvingCallbacks;
+use crate::scope::ScopeHooks;
+use crate::shutdown::TerminateHook;
+use crate::store::{Instance, InstanceStore, MemoryStore, OrderedStore, ResolutionCounts};
+use crate::tags::TaggedService;
+use crate::telemetry::Sampling;
+use crate::trace::{self, BuildingGuard, ResolvingGuard, ScopeGuard};
+use crate::values::ValueBindings;
+use crate::versioned::VersionedBindings;
+use crate::warm_pool::WarmPools;
 use luminos_contracts::container::{Contract, Injectable};
 use luminos_contracts::support::ServiceProvider;
 use std::any::{Any, TypeId};
-use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
+use std::cell::RefCell;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::ops::{Deref, DerefMut};
+use std::panic::Location;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+use std::time::{Duration, Instant};
 
-type Factory = Arc<dyn Fn(&Container) -> Arc<dyn Any + Send + Sync> + Send + Sync>;
+pub(crate) type Factory = Arc<dyn Fn(&Container) -> Arc<dyn Any + Send + Sync> + Send + Sync>;
+
+#[derive(Clone)]
+pub(crate) struct Binding {
+    pub(crate) factory: Factory,
+    pub(crate) type_name: &'static str,
+    pub(crate) implicit: bool,
+    pub(crate) lifetime: Lifetime,
+    pub(crate) location: &'static Location<'static>,
+}
+
+pub(crate) struct ProviderEntry {
+    pub(crate) name: Option<&'static str>,
+    pub(crate) provider: Box<dyn ServiceProvider<Container>>,
+    pub(crate) bindings: Vec<TypeId>,
+    pub(crate) priority: i32,
+    pub(crate) depends_on: Vec<&'static str>,
+    pub(crate) parallel: bool,
+    pub(crate) location: &'static Location<'static>,
+    pub(crate) elapsed: Duration,
+    pub(crate) state: StateSlots,
+    pub(crate) terminate: Option<TerminateHook>,
+}
+
+impl ProviderEntry {
+    #[track_caller]
+    pub(crate) fn new(
+        name: Option<&'static str>,
+        provider: Box<dyn ServiceProvider<Container>>,
+    ) -> Self {
+        Self {
+            name,
+            provider,
+            bindings: Vec::new(),
+            priority: 0,
+            depends_on: Vec::new(),
+            parallel: false,
+            location: Location::caller(),
+            elapsed: Duration::ZERO,
+            state: StateSlots::default(),
+            terminate: None,
+        }
+    }
+}
+
+type MissingHandler = Arc<dyn Fn(&'static str, &Container) -> Option<Instance> + Send + Sync>;
+
+static NEXT_CONTAINER_ID: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    /// Bindings made by the provider currently registering on this thread.
+    static REGISTERING: RefCell<Option<Vec<TypeId>>> = const { RefCell::new(None) };
+}
+
+/// Restores the outer collector even if a provider's `register` panics.
+struct RegisteringGuard {
+    previous: Option<Option<Vec<TypeId>>>,
+}
+
+impl RegisteringGuard {
+    fn start() -> Self {
+        Self {
+            previous: Some(REGISTERING.with(|r| r.replace(Some(Vec::new())))),
+        }
+    }
+
+    fn finish(mut self) -> Vec<TypeId> {
+        let previous = self.previous.take().flatten();
+        REGISTERING
+            .with(|r| r.replace(previous))
+            .unwrap_or_default()
+    }
+}
+
+impl Drop for RegisteringGuard {
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous.take() {
+            REGISTERING.with(|r| r.replace(previous));
+        }
+    }
+}
+
+/// Providers taken out of the container while their `register`/`boot` run,
+/// so that code may resolve, spawn resolving threads or add providers without
+/// deadlocking. Put back on drop, ahead of any provider added meanwhile.
+pub(crate) struct CheckedOutProviders<'a> {
+    container: &'a Container,
+    entries: Vec<ProviderEntry>,
+}
+
+impl Deref for CheckedOutProviders<'_> {
+    type Target = Vec<ProviderEntry>;
+
+    fn deref(&self) -> &Vec<ProviderEntry> {
+        &self.entries
+    }
+}
+
+impl DerefMut for CheckedOutProviders<'_> {
+    fn deref_mut(&mut self) -> &mut Vec<ProviderEntry> {
+        &mut self.entries
+    }
+}
+
+impl Drop for CheckedOutProviders<'_> {
+    fn drop(&mut self) {
+        let mut stored = self
+            .container
+            .providers
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let added = std::mem::take(&mut *stored);
+        *stored = std::mem::take(&mut self.entries);
+        stored.extend(added);
+    }
+}
 
-#[derive(Default)]
 pub struct Container {
-    instances: Mutex<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>,
-    factories: Mutex<HashMap<TypeId, Factory>>,
-    providers: Mutex<Vec<Box<dyn ServiceProvider<Container>>>>,
+    pub(crate) id: u64,
+    /// Dropped with the container; thread-local caches hold it weakly.
+    pub(crate) alive: Arc<()>,
+    pub(crate) instances: Box<dyn InstanceStore>,
+    internals: MemoryStore,
+    pub(crate) factories: Mutex<Arc<HashMap<TypeId, Binding>>>,
+    pub(crate) local_factories: Mutex<HashMap<TypeId, LocalFactory>>,
+    pub(crate) dependencies: Mutex<DependencyEdges>,
+    pub(crate) resolutions: ResolutionCounts,
+    pub(crate) created_at: Instant,
+    pub(crate) boot_parallelism: AtomicUsize,
+    pub(crate) configurers: Mutex<HashMap<TypeId, Vec<Arc<dyn Any + Send + Sync>>>>,
+    pub(crate) options_validators: Mutex<Vec<(TypeId, OptionsValidator)>>,
+    pub(crate) versions: Mutex<HashMap<TypeId, VersionedBindings>>,
+    pub(crate) named: Mutex<HashMap<TypeId, NamedBindings>>,
+    pub(crate) contextual: Mutex<ContextualBindings>,
+    pub(crate) tags: Mutex<HashMap<String, Vec<TaggedService>>>,
+    pub(crate) multi: Mutex<HashMap<TypeId, MultiBindings>>,
+    pub(crate) decorators: Mutex<Decorators>,
+    pub(crate) resolving_callbacks: RwLock<ResolvingCallbacks>,
+    pub(crate) values: RwLock<ValueBindings>,
+    pub(crate) binding_waiters: Mutex<BindingWaiters>,
+    init_gates: Mutex<HashMap<TypeId, Arc<Mutex<()>>>>,
+    pub(crate) escape_audit: EscapeAudit,
+    pub(crate) frozen: AtomicBool,
+    pub(crate) warm_pools: RwLock<WarmPools>,
+    pub(crate) persistent: Mutex<PersistentStates>,
+    pub(crate) declared_capabilities: Mutex<BTreeSet<String>>,
+    pub(crate) refcounts: Mutex<RefcountSamples>,
+    pub(crate) sampling: Sampling,
+    pub(crate) profiling: Mutex<Option<Vec<ProfileEntry>>>,
+    pub(crate) budget: Mutex<Option<Duration>>,
+    pub(crate) budget_report: Mutex<Option<BudgetReport>>,
+    pub(crate) scope_hooks: Mutex<ScopeHooks>,
+    pub(crate) lifetime_overrides: Mutex<Vec<(String, Lifetime)>>,
+    pub(crate) sealed: Mutex<HashSet<TypeId>>,
+    pub(crate) bulkheads: Mutex<HashMap<TypeId, Arc<Bulkhead>>>,
+    pub(crate) providers: Mutex<Vec<ProviderEntry>>,
+    pub(crate) async_providers: Mutex<Vec<Arc<dyn AsyncServiceProvider>>>,
+    pub(crate) deferred: Mutex<Vec<DeferredEntry>>,
+    manifests: Mutex<Vec<AddedManifest>>,
+    missing_handler: Mutex<Option<MissingHandler>>,
 }
 
 impl Container {
     pub fn new() -> Self {
+        Self::with_store(MemoryStore::new())
+    }
+
+    /// A container whose instance snapshots follow insertion order and whose
+    /// providers always register one at a time, for reproducible boot logs
+    /// and teardown order in golden-output tests.
+    pub fn deterministic() -> Self {
+        let container = Self::with_store(OrderedStore::new());
+        container.set_boot_parallelism(1);
+        container
+    }
+
+    pub fn with_store(store: impl InstanceStore + 'static) -> Self {
+        Self::with_boxed_store(Box::new(store))
+    }
+
+    fn with_boxed_store(instances: Box<dyn InstanceStore>) -> Self {
         Self {
-            instances: Mutex::new(HashMap::new()),
-            factories: Mutex::new(HashMap::new()),
+            id: NEXT_CONTAINER_ID.fetch_add(1, Ordering::Relaxed),
+            alive: Arc::new(()),
+            instances,
+            internals: MemoryStore::new(),
+            factories: Mutex::new(Arc::new(HashMap::new())),
+            local_factories: Mutex::new(HashMap::new()),
+            dependencies: Mutex::new(HashMap::new()),
+            resolutions: ResolutionCounts::default(),
+            created_at: Instant::now(),
+            boot_parallelism: AtomicUsize::new(0),
+            configurers: Mutex::new(HashMap::new()),
+            options_validators: Mutex::new(Vec::new()),
+            versions: Mutex::new(HashMap::new()),
+            named: Mutex::new(HashMap::new()),
+            contextual: Mutex::new(HashMap::new()),
+            tags: Mutex::new(HashMap::new()),
+            multi: Mutex::new(HashMap::new()),
+            decorators: Mutex::new(HashMap::new()),
+            resolving_callbacks: RwLock::new(ResolvingCallbacks::default()),
+            values: RwLock::new(HashMap::new()),
+            binding_waiters: Mutex::new(HashMap::new()),
+            init_gates: Mutex::new(HashMap::new()),
+            escape_audit: EscapeAudit::default(),
+            frozen: AtomicBool::new(false),
+            warm_pools: RwLock::new(HashMap::new()),
+            persistent: Mutex::new(PersistentStates::default()),
+            declared_capabilities: Mutex::new(BTreeSet::new()),
+            refcounts: Mutex::new(HashMap::new()),
+            sampling: Sampling::default(),
+            profiling: Mutex::new(None),
+            budget: Mutex::new(None),
+            budget_report: Mutex::new(None),
+            scope_hooks: Mutex::new(ScopeHooks::default()),
+            lifetime_overrides: Mutex::new(Vec::new()),
+            sealed: Mutex::new(HashSet::new()),
+            bulkheads: Mutex::new(HashMap::new()),
             providers: Mutex::new(Vec::new()),
+            async_providers: Mutex::new(Vec::new()),
+            deferred: Mutex::new(Vec::new()),
+            manifests: Mutex::new(Vec::new()),
+            missing_handler: Mutex::new(None),
         }
     }
-}
 
-impl Contract for Container {
-    fn bind<T, F>(&self, factory: F)
+    /// Last-chance hook consulted when a type has no binding and its
+    /// `Injectable` registration did not provide one either.
+    pub fn on_missing<F>(&self, handler: F) -> &Self
     where
-        T: Sized + Send + Sync + 'static,
-        F: Fn(&Container) -> Arc<T> + Send + Sync + 'static,
+        F: Fn(&'static str, &Container) -> Option<Instance> + Send + Sync + 'static,
     {
-        let type_id = TypeId::of::<T>();
-        let boxed_factory: Factory = Arc::new(move |c| factory(c) as Arc<dyn Any + Send + Sync>);
+        *self.missing_handler.lock().unwrap() = Some(Arc::new(handler));
+        self
+    }
+
+    /// Registers an already-built value, e.g. configuration parsed in `main`.
+    /// Every resolution of `T` returns this same `Arc`.
+    #[track_caller]
+    pub fn instance<T>(&self, value: Arc<T>) -> &Self
+    where
+        T: Send + Sync + 'static,
+    {
+        let shared = value.clone();
+        self.bind::<T, _>(move |_| shared.clone());
+        self.instances.insert(TypeId::of::<T>(), value);
+        self
+    }
+
+    /// Cached instance or freshly built one, without `Injectable` auto-registration.
+    pub(crate) fn resolve_instance(&self, type_id: TypeId) -> Option<Instance> {
+        // Scope locals and scoped instances shadow parent bindings at every
+        // depth, not just for the type the scope was asked for.
+        if let Some(store) = trace::current_scope_store()
+            && let Some(inst) = store.get(&type_id)
+        {
+            self.record_resolution(type_id);
+            self.record_scoped_handout(type_id);
+            return Some(inst);
+        }
+
+        if let Some(inst) = self.instances.get(&type_id) {
+            self.record_resolution(type_id);
+            #[cfg(any(feature = "tracing", feature = "metrics"))]
+            self.record_telemetry(type_id, None, None);
+            return Some(inst);
+        }
+
+        if let Some(store) = self.scoped_store(type_id) {
+            self.record_scoped_handout(type_id);
+            let _args = ArgsGuard::hide();
+            let (built, _) = self.build_instance(type_id, false)?;
+            store.insert(type_id, built.clone());
+            return Some(built);
+        }
+
+        if let Some(inst) = self.take_warm(type_id) {
+            return Some(inst);
+        }
+
+        // Concurrent first resolutions of a singleton wait on a per-type gate
+        // so the factory runs once. Re-entry on this thread skips the gate and
+        // is reported by cycle detection instead of deadlocking.
+        let singleton = self.binding_lifetime(type_id) == Some(Lifetime::Singleton);
+        let gate = (singleton && !trace::is_building(type_id)).then(|| self.init_gate(type_id));
+        let _init = gate
+            .as_ref()
+            .map(|gate| gate.lock().unwrap_or_else(PoisonError::into_inner));
+        if gate.is_some()
+            && let Some(inst) = self.instances.get(&type_id)
+        {
+            self.record_resolution(type_id);
+            return Some(inst);
+        }
+
+        let _args = singleton.then(ArgsGuard::hide);
+        let _scope = singleton.then(ScopeGuard::hide);
+        let (built, lifetime) = self.build_instance(type_id, true)?;
+        if lifetime == Lifetime::Singleton {
+            self.instances.insert(type_id, built.clone());
+        }
+        Some(built)
+    }
+
+    fn binding_lifetime(&self, type_id: TypeId) -> Option<Lifetime> {
         self.factories
             .lock()
             .unwrap()
-            .insert(type_id, boxed_factory);
+            .get(&type_id)
+            .map(|binding| binding.lifetime)
     }
 
-    fn resolve<T>(&self) -> Arc<T>
+    fn init_gate(&self, type_id: TypeId) -> Arc<Mutex<()>> {
+        self.init_gates
+            .lock()
+            .unwrap()
+            .entry(type_id)
+            .or_default()
+            .clone()
+    }
+
+    /// Cache of the active scope when `type_id` is bound as scoped.
+    fn scoped_store(&self, type_id: TypeId) -> Option<Arc<MemoryStore>> {
+        let type_name = self
+            .factories
+            .lock()
+            .unwrap()
+            .get(&type_id)
+            .filter(|binding| binding.lifetime == Lifetime::Scoped)
+            .map(|binding| binding.type_name)?;
+
+        Some(trace::current_scope_store().unwrap_or_else(|| {
+            if trace::scope_hidden() {
+                panic!(
+                    "Failed to resolve type: {type_name:?} (scoped service resolved while building a singleton)"
+                )
+            }
+            panic!(
+                "Failed to resolve type: {type_name:?} (scoped service resolved outside a scope)"
+            )
+        }))
+    }
+
+    /// Runs the binding's factory for `type_id`. With `reuse_cached`, an
+    /// instance cached by another thread while waiting on a bulkhead wins.
+    pub(crate) fn build_instance(
+        &self,
+        type_id: TypeId,
+        reuse_cached: bool,
+    ) -> Option<(Instance, Lifetime)> {
+        let binding = self.factories.lock().unwrap().get(&type_id).cloned();
+        let binding = match binding {
+            Some(binding) => binding,
+            None if self.load_deferred(type_id) => {
+                return self.build_instance(type_id, reuse_cached);
+            }
+            None => return None,
+        };
+        self.record_resolution(type_id);
+        let _building = BuildingGuard::enter(type_id, binding.type_name);
+
+        let bulkhead = self.bulkheads.lock().unwrap().get(&type_id).cloned();
+        let _permit = match &bulkhead {
+            Some(bulkhead) => {
+                let permit = bulkhead.acquire().unwrap_or_else(|| {
+                    panic!(
+                        "Failed to resolve type: {:?} (concurrent build limit reached)",
+                        binding.type_name
+                    )
+                });
+                if reuse_cached && let Some(inst) = self.instances.get(&type_id) {
+                    return Some((inst, binding.lifetime));
+                }
+                Some(permit)
+            }
+            None => None,
+        };
+
+        #[cfg(feature = "chaos")]
+        self.inject_construction_chaos(type_id, binding.type_name);
+
+        let started = Instant::now();
+        let _resolving = ResolvingGuard::enter(binding.type_name);
+        let built = (binding.factory)(self);
+        let built = self.finish_build(type_id, built);
+        if let Some(profile) = self.profiling.lock().unwrap().as_mut() {
+            profile.push(ProfileEntry {
+                type_name: binding.type_name.to_string(),
+                duration: started.elapsed(),
+            });
+        }
+        #[cfg(any(feature = "tracing", feature = "metrics"))]
+        self.record_telemetry(type_id, Some(binding.type_name), Some(started.elapsed()));
+        Some((built, binding.lifetime))
+    }
+
+    /// Runs resolving callbacks and decorators on a freshly built instance.
+    pub(crate) fn finish_build(&self, type_id: TypeId, built: Instance) -> Instance {
+        self.fire_resolving(type_id, &built, false);
+        let built = self.apply_decorators(type_id, built);
+        self.fire_resolving(type_id, &built, true);
+        built
+    }
+
+    /// Full resolution path behind `resolve`, reporting failures instead of
+    /// panicking. Factory panics still propagate.
+    pub(crate) fn resolve_checked<T>(&self) -> Result<Arc<T>, ContainerError>
+    where
+        T: Injectable + Send + Sync + 'static,
+    {
+        let resolved = self.lookup_checked::<T>();
+        if let Ok(inst) = &resolved {
+            self.fire_resolved(std::any::type_name::<T>(), &**inst);
+        }
+        resolved
+    }
+
+    fn lookup_checked<T>(&self) -> Result<Arc<T>, ContainerError>
     where
         T: Injectable + Send + Sync + 'static,
     {
         let type_id = TypeId::of::<T>();
+        self.record_dependency(std::any::type_name::<T>());
+
+        if let Some(inst) = self.contextual::<T>() {
+            return Ok(inst);
+        }
 
-        if let Some(inst) = self.instances.lock().unwrap().get(&type_id) {
-            return inst.clone().downcast::<T>().unwrap();
+        if let Some(inst) = self.resolve_instance(type_id) {
+            return self.downcast::<T>(inst);
         }
 
+        T::__register(self);
         {
-            let factories = self.factories.lock().unwrap();
-            if let Some(factory) = factories.get(&type_id) {
-                let factory = factory.clone();
-                drop(factories);
-
-                let built = factory(self);
-                self.instances
-                    .lock()
-                    .unwrap()
-                    .insert(type_id, built.clone());
-                return built.downcast::<T>().unwrap();
+            let mut factories = self.factories.lock().unwrap();
+            if let Some(binding) = Arc::make_mut(&mut factories).get_mut(&type_id) {
+                binding.implicit = true;
             }
         }
 
-        T::__register(self);
+        if let Some(inst) = self.resolve_instance(type_id) {
+            return self.downcast::<T>(inst);
+        }
 
-        let factories = self.factories.lock().unwrap();
-        if let Some(factory) = factories.get(&type_id) {
-            let factory = factory.clone();
-            drop(factories);
+        let handler = self.missing_handler.lock().unwrap().clone();
+        if let Some(built) = handler.and_then(|h| h(std::any::type_name::<T>(), self))
+            && let Ok(inst) = built.clone().downcast::<T>()
+        {
+            self.instances.insert(type_id, built);
+            return Ok(inst);
+        }
 
-            let built = factory(self);
-            self.instances
-                .lock()
-                .unwrap()
-                .insert(type_id, built.clone());
-            return built.downcast::<T>().unwrap();
+        Err(ContainerError::MissingBinding {
+            type_name: std::any::type_name::<T>(),
+        })
+    }
+
+    fn downcast<T>(&self, inst: Instance) -> Result<Arc<T>, ContainerError>
+    where
+        T: Send + Sync + 'static,
+    {
+        inst.downcast::<T>()
+            .map_err(|_| ContainerError::DowncastFailed {
+                type_name: std::any::type_name::<T>(),
+                bound_at: self.binding_location::<T>(),
+            })
+    }
+
+    pub(crate) fn record_resolution(&self, type_id: TypeId) {
+        self.resolutions.increment(type_id);
+    }
+
+    /// Runs `T`'s factory once more, whatever its lifetime, without caching
+    /// the result.
+    pub(crate) fn build_uncached<T>(&self) -> Arc<T>
+    where
+        T: Injectable + Send + Sync + 'static,
+    {
+        let type_id = TypeId::of::<T>();
+        if !self.factories.lock().unwrap().contains_key(&type_id) {
+            T::__register(self);
         }
 
-        panic!("Failed to resolve type: {:?}", std::any::type_name::<T>());
+        self.build_instance(type_id, false)
+            .map(|(inst, _)| inst)
+            .unwrap_or_else(|| panic!("Failed to resolve type: {:?}", std::any::type_name::<T>()))
+            .downcast::<T>()
+            .unwrap()
+    }
+
+    /// Whether a cached instance can be handed out directly: no active scope,
+    /// contextual binding or global resolved callback could change or
+    /// observe the result of a regular resolve.
+    pub(crate) fn cache_is_authoritative(&self) -> bool {
+        trace::current_scope_store().is_none()
+            && self.contextual.lock().unwrap().is_empty()
+            && self.resolving_callbacks.read().unwrap().global.is_empty()
     }
 
+    pub(crate) fn resolve_bound<T>(&self) -> Option<Arc<T>>
+    where
+        T: Send + Sync + 'static,
+    {
+        self.record_dependency(std::any::type_name::<T>());
+        self.resolve_instance(TypeId::of::<T>())
+            .map(|inst| inst.downcast::<T>().unwrap())
+    }
+
+    /// Container-owned state (secret store, environment, config tree, ...),
+    /// kept out of the instance store so flushes and overrides leave it alone.
+    pub(crate) fn internal<T>(&self) -> Option<Arc<T>>
+    where
+        T: Send + Sync + 'static,
+    {
+        self.internals
+            .get(&TypeId::of::<T>())
+            .and_then(|inst| inst.downcast::<T>().ok())
+    }
+
+    pub(crate) fn set_internal<T>(&self, value: Arc<T>)
+    where
+        T: Send + Sync + 'static,
+    {
+        self.internals.insert(TypeId::of::<T>(), value);
+    }
+
+    pub(crate) fn internal_or_insert_with<T>(&self, init: impl FnOnce() -> T) -> Arc<T>
+    where
+        T: Send + Sync + 'static,
+    {
+        if let Some(value) = self.internal::<T>() {
+            return value;
+        }
+
+        let value = Arc::new(init());
+        self.set_internal(value.clone());
+        value
+    }
+
+    pub(crate) fn remove_internal<T>(&self)
+    where
+        T: Send + Sync + 'static,
+    {
+        self.internals.remove(&TypeId::of::<T>());
+    }
+
+    /// Where the current binding of `T` was registered.
+    pub fn binding_location<T>(&self) -> Option<&'static Location<'static>>
+    where
+        T: Send + Sync + 'static,
+    {
+        self.factories
+            .lock()
+            .unwrap()
+            .get(&TypeId::of::<T>())
+            .map(|binding| binding.location)
+    }
+
+    /// Every provider with the place it was added, in boot order.
+    pub fn provider_locations(&self) -> Vec<(Option<&'static str>, &'static Location<'static>)> {
+        self.providers
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|entry| (entry.name, entry.location))
+            .collect()
+    }
+
+    /// A new container sharing this one's bindings until either side changes
+    /// them, backed by the same kind of instance store. Cached instances and
+    /// providers are not carried over.
+    pub fn clone_cow(&self) -> Self {
+        let container = Self::with_boxed_store(self.instances.new_empty());
+        container.boot_parallelism.store(
+            self.boot_parallelism.load(Ordering::Relaxed),
+            Ordering::Relaxed,
+        );
+        *container.factories.lock().unwrap() = self.factories.lock().unwrap().clone();
+        *container.local_factories.lock().unwrap() = self.local_factories.lock().unwrap().clone();
+        *container.configurers.lock().unwrap() = self.configurers.lock().unwrap().clone();
+        *container.options_validators.lock().unwrap() =
+            self.options_validators.lock().unwrap().clone();
+        *container.versions.lock().unwrap() = self
+            .versions
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(type_id, versions)| (*type_id, versions.without_instances()))
+            .collect();
+        *container.named.lock().unwrap() = self
+            .named
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(type_id, named)| (*type_id, named.without_instances()))
+            .collect();
+        *container.multi.lock().unwrap() = self
+            .multi
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(type_id, multi)| (*type_id, multi.without_instances()))
+            .collect();
+        *container.contextual.lock().unwrap() = self.contextual.lock().unwrap().clone();
+        *container.tags.lock().unwrap() = self.tags.lock().unwrap().clone();
+        *container.values.write().unwrap() = self.values.read().unwrap().clone();
+        *container.scope_hooks.lock().unwrap() = self.scope_hooks.lock().unwrap().clone();
+        *container.lifetime_overrides.lock().unwrap() =
+            self.lifetime_overrides.lock().unwrap().clone();
+        *container.sealed.lock().unwrap() = self.sealed.lock().unwrap().clone();
+        *container.bulkheads.lock().unwrap() = self.bulkheads.lock().unwrap().clone();
+        *container.decorators.lock().unwrap() = self.decorators.lock().unwrap().clone();
+        *container.resolving_callbacks.write().unwrap() =
+            self.resolving_callbacks.read().unwrap().clone();
+        *container.budget.lock().unwrap() = *self.budget.lock().unwrap();
+        *container.manifests.lock().unwrap() = self.manifests.lock().unwrap().clone();
+        *container.missing_handler.lock().unwrap() = self.missing_handler.lock().unwrap().clone();
+        container
+    }
+
+    /// A [`Container::clone_cow`] copy that also shares this container's
+    /// internals and deferred providers, so services built in it see the
+    /// same environment, secrets and configuration.
+    pub(crate) fn staging_copy(&self) -> Self {
+        let staging = self.clone_cow();
+        for (type_id, value) in self.internals.snapshot() {
+            staging.internals.insert(type_id, value);
+        }
+        *staging.deferred.lock().unwrap() = self
+            .deferred
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(DeferredEntry::share)
+            .collect();
+        staging
+    }
+
+    /// Locks the current binding of `T`; any later attempt to rebind it panics.
+    pub fn seal<T>(&self) -> &Self
+    where
+        T: Send + Sync + 'static,
+    {
+        self.sealed.lock().unwrap().insert(TypeId::of::<T>());
+        self
+    }
+
+    #[track_caller]
+    pub fn bind_sealed<T, F>(&self, factory: F) -> &Self
+    where
+        T: Send + Sync + 'static,
+        F: Fn(&Container) -> Arc<T> + Send + Sync + 'static,
+    {
+        self.bind::<T, F>(factory);
+        self.seal::<T>()
+    }
+
+    pub fn is_sealed<T>(&self) -> bool
+    where
+        T: Send + Sync + 'static,
+    {
+        self.sealed.lock().unwrap().contains(&TypeId::of::<T>())
+    }
+
+    #[track_caller]
+    pub fn add_provider_with_manifest(
+        &self,
+        provider: Box<dyn ServiceProvider<Container>>,
+        manifest: ProviderManifest,
+    ) -> Result<&Self, Vec<ManifestConflict>> {
+        let name = manifest.name;
+        let added = AddedManifest {
+            manifest,
+            added_at: Location::caller(),
+        };
+        {
+            let mut manifests = self.manifests.lock().unwrap();
+            let conflicts = manifest::conflicts_with(&manifests, &added);
+            if !conflicts.is_empty() {
+                return Err(conflicts);
+            }
+            manifests.push(added);
+        }
+
+        Ok(self.add_named_provider(name, provider))
+    }
+
+    #[track_caller]
+    pub fn add_named_provider(
+        &self,
+        name: &'static str,
+        provider: Box<dyn ServiceProvider<Container>>,
+    ) -> &Self {
+        self.providers
+            .lock()
+            .unwrap()
+            .push(ProviderEntry::new(Some(name), provider));
+        self
+    }
+
+    /// Adds a provider that registers and boots ahead of lower-priority ones.
+    /// Providers default to priority 0; ties keep insertion order.
+    #[track_caller]
+    pub fn add_provider_with_priority(
+        &self,
+        provider: Box<dyn ServiceProvider<Container>>,
+        priority: i32,
+    ) -> &Self {
+        let mut entry = ProviderEntry::new(None, provider);
+        entry.priority = priority;
+        self.providers.lock().unwrap().push(entry);
+        self
+    }
+
+    pub(crate) fn checkout_providers(&self) -> CheckedOutProviders<'_> {
+        CheckedOutProviders {
+            container: self,
+            entries: std::mem::take(&mut *self.providers.lock().unwrap()),
+        }
+    }
+
+    /// Runs `register` for one provider, remembering which bindings it added.
+    pub(crate) fn register_provider(&self, entry: &mut ProviderEntry) {
+        let started = Instant::now();
+        let _state = ProviderStateGuard::enter(entry.state.clone());
+        let registering = RegisteringGuard::start();
+        entry.provider.register(self);
+        entry.bindings = registering.finish();
+        entry.elapsed += started.elapsed();
+    }
+
+    pub(crate) fn boot_provider(&self, entry: &mut ProviderEntry) {
+        let started = Instant::now();
+        let _state = ProviderStateGuard::enter(entry.state.clone());
+        entry.provider.boot(self);
+        entry.elapsed += started.elapsed();
+    }
+
+    /// Adds a provider whose `register` only binds and may therefore run on
+    /// a worker thread alongside other parallel providers. All registration
+    /// finishes before any provider boots.
+    #[track_caller]
+    pub fn add_parallel_provider(&self, provider: Box<dyn ServiceProvider<Container>>) -> &Self {
+        let mut entry = ProviderEntry::new(None, provider);
+        entry.parallel = true;
+        self.providers.lock().unwrap().push(entry);
+        self
+    }
+
+    /// Caps the worker threads used for parallel registration; `0` (the
+    /// default) uses the available parallelism.
+    pub fn set_boot_parallelism(&self, threads: usize) -> &Self {
+        self.boot_parallelism.store(threads, Ordering::Relaxed);
+        self
+    }
+
+    fn register_in_parallel(&self, entries: Vec<&mut ProviderEntry>) {
+        let threads = match self.boot_parallelism.load(Ordering::Relaxed) {
+            0 => std::thread::available_parallelism().map_or(1, |n| n.get()),
+            n => n,
+        }
+        .min(entries.len());
+
+        if threads <= 1 {
+            for entry in entries {
+                self.register_provider(entry);
+            }
+            return;
+        }
+
+        let mut buckets: Vec<Vec<&mut ProviderEntry>> = (0..threads).map(|_| Vec::new()).collect();
+        for (index, entry) in entries.into_iter().enumerate() {
+            buckets[index % threads].push(entry);
+        }
+
+        std::thread::scope(|s| {
+            for bucket in buckets {
+                s.spawn(move || {
+                    for entry in bucket {
+                        self.register_provider(entry);
+                    }
+                });
+            }
+        });
+    }
+
+    /// Re-runs `register` and `boot` for the named providers, dropping the
+    /// singletons they bound while leaving every other instance cached.
+    ///
+    /// Services that already captured one of the dropped singletons keep
+    /// their old copy until they are rebuilt themselves.
+    pub fn reboot_providers(&self, names: &[&str]) -> &Self {
+        let mut providers = self.checkout_providers();
+
+        for name in names {
+            if !providers.iter().any(|entry| entry.name == Some(*name)) {
+                panic!("Failed to reboot provider: {name:?} is not registered");
+            }
+        }
+
+        let selected = |entry: &ProviderEntry| entry.name.is_some_and(|n| names.contains(&n));
+
+        for entry in providers.iter_mut().filter(|entry| selected(entry)) {
+            for type_id in entry.bindings.drain(..) {
+                self.instances.remove(&type_id);
+            }
+            self.register_provider(entry);
+        }
+
+        for entry in providers.iter_mut().filter(|entry| selected(entry)) {
+            self.boot_provider(entry);
+        }
+
+        self
+    }
+
+    pub fn check_manifests(&self) -> Result<(), Vec<ManifestConflict>> {
+        let conflicts = manifest::unmet_requirements(&self.manifests.lock().unwrap());
+        if conflicts.is_empty() {
+            Ok(())
+        } else {
+            Err(conflicts)
+        }
+    }
+}
+
+impl Drop for Container {
+    fn drop(&mut self) {
+        local::forget_thread_locals(self.id);
+    }
+}
+
+impl Default for Container {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Contract for Container {
+    #[track_caller]
+    fn bind<T, F>(&self, factory: F)
+    where
+        T: Sized + Send + Sync + 'static,
+        F: Fn(&Container) -> Arc<T> + Send + Sync + 'static,
+    {
+        let type_id = TypeId::of::<T>();
+        if self.frozen.load(Ordering::SeqCst) {
+            panic!(
+                "Cannot bind type: {:?} (container is frozen)",
+                std::any::type_name::<T>()
+            );
+        }
+        if self.sealed.lock().unwrap().contains(&type_id) {
+            let error = ContainerError::Sealed {
+                type_name: std::any::type_name::<T>(),
+                bound_at: self.binding_location::<T>(),
+            };
+            panic!("{error}");
+        }
+
+        REGISTERING.with(|r| {
+            if let Some(bindings) = r.borrow_mut().as_mut() {
+                bindings.push(type_id);
+            }
+        });
+
+        let boxed_factory: Factory = Arc::new(move |c| factory(c) as Arc<dyn Any + Send + Sync>);
+        Arc::make_mut(&mut *self.factories.lock().unwrap()).insert(
+            type_id,
+            Binding {
+                factory: boxed_factory,
+                type_name: std::any::type_name::<T>(),
+                implicit: false,
+                lifetime: self
+                    .lifetime_override(std::any::type_name::<T>())
+                    .unwrap_or_default(),
+                location: Location::caller(),
+            },
+        );
+        self.invalidate_warm(type_id);
+        self.wake_binding_waiters(type_id);
+    }
+
+    fn resolve<T>(&self) -> Arc<T>
+    where
+        T: Injectable + Send + Sync + 'static,
+    {
+        self.resolve_checked::<T>()
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    #[track_caller]
     fn add_provider(&self, provider: Box<dyn ServiceProvider<Self> + 'static>) -> &Self {
-        self.providers.lock().unwrap().push(provider);
+        self.providers
+            .lock()
+            .unwrap()
+            .push(ProviderEntry::new(None, provider));
         self
     }
 
+    #[track_caller]
     fn add_providers(&self, providers: Vec<Box<dyn ServiceProvider<Container>>>) -> &Self {
         for provider in providers {
             self.add_provider(provider);
@@ -94,19 +951,42 @@ impl Contract for Container {
     }
 
     fn boot(&self) -> &Self {
-        let providers = self.providers.lock().unwrap();
+        if let Err(conflicts) = self.check_manifests() {
+            let report: Vec<String> = conflicts.iter().map(ToString::to_string).collect();
+            panic!("Provider manifest conflicts:\n  {}", report.join("\n  "));
+        }
+
+        let budget = self.start_budget();
+        self.environment();
+
+        let mut providers = self.checkout_providers();
+        providers.sort_by_key(|entry| std::cmp::Reverse(entry.priority));
+        if let Err(err) = ordering::order_providers(&mut providers) {
+            panic!("Failed to order providers: {err}");
+        }
+
+        for entry in providers.iter_mut().filter(|entry| !entry.parallel) {
+            self.register_provider(entry);
+        }
+        self.register_in_parallel(providers.iter_mut().filter(|e| e.parallel).collect());
+
+        if let Err(errors) = self.validate_options() {
+            panic!("{errors}");
+        }
+        self.refresh_capabilities(&providers);
 
-        for provider in providers.iter() {
-            provider.register(self);
+        for entry in providers.iter_mut() {
+            self.boot_provider(entry);
         }
 
-        for provider in providers.iter() {
-            provider.boot(self);
+        if let Some(budget) = budget {
+            self.finish_budget(budget, &providers);
         }
 
         self
     }
 
+    #[track_caller]
     fn with_provider(self, provider: Box<dyn ServiceProvider<Self> + 'static>) -> Self {
         self.add_provider(provider);
         self
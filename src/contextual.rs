@@ -0,0 +1,86 @@
+use crate::container::Container;
+use crate::store::Instance;
+use crate::trace;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+type ContextualFactory = Arc<dyn Fn(&Container) -> Instance + Send + Sync>;
+
+/// Overrides keyed by consumer type name and the `TypeId` of `Arc<T>` for
+/// what it needs, matching how trait objects are bound by `bind_trait`.
+/// Each entry keeps the needed type's name for diagnostics.
+pub(crate) type ContextualBindings =
+    HashMap<(&'static str, TypeId), (&'static str, ContextualFactory)>;
+
+pub struct When<'a, C: ?Sized> {
+    container: &'a Container,
+    consumer: PhantomData<fn() -> Box<C>>,
+}
+
+pub struct Needs<'a, C: ?Sized, T: ?Sized> {
+    container: &'a Container,
+    consumer: PhantomData<fn() -> Box<C>>,
+    needed: PhantomData<fn() -> Box<T>>,
+}
+
+impl<'a, C: ?Sized + 'static> When<'a, C> {
+    pub fn needs<T: ?Sized + Send + Sync + 'static>(self) -> Needs<'a, C, T> {
+        Needs {
+            container: self.container,
+            consumer: PhantomData,
+            needed: PhantomData,
+        }
+    }
+}
+
+impl<'a, C: ?Sized + 'static, T: ?Sized + Send + Sync + 'static> Needs<'a, C, T> {
+    /// Builds what `C` gets for `T`; runs each time a `C` is constructed.
+    pub fn give<F>(self, factory: F) -> &'a Container
+    where
+        F: Fn(&Container) -> Arc<T> + Send + Sync + 'static,
+    {
+        let factory: ContextualFactory = Arc::new(move |c| Arc::new(factory(c)) as Instance);
+        self.container.contextual.lock().unwrap().insert(
+            (std::any::type_name::<C>(), TypeId::of::<Arc<T>>()),
+            (std::any::type_name::<T>(), factory),
+        );
+        self.container
+    }
+}
+
+impl Container {
+    /// Starts a contextual binding: while a `C` is being built, resolving
+    /// the type named by `needs` yields the `give` factory's value instead
+    /// of the regular binding.
+    ///
+    /// ```ignore
+    /// container
+    ///     .when::<ReportService>()
+    ///     .needs::<dyn Storage>()
+    ///     .give(|c| c.resolve::<S3Storage>());
+    /// ```
+    pub fn when<C: ?Sized + 'static>(&self) -> When<'_, C> {
+        When {
+            container: self,
+            consumer: PhantomData,
+        }
+    }
+
+    /// The contextual value of `T` for the factory running on this thread, if any.
+    pub(crate) fn contextual<T>(&self) -> Option<Arc<T>>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        let consumer = trace::current_resolving()?;
+        let (_, factory) = self
+            .contextual
+            .lock()
+            .unwrap()
+            .get(&(consumer, TypeId::of::<Arc<T>>()))
+            .cloned()?;
+
+        factory(self).downcast_ref::<Arc<T>>().cloned()
+    }
+}
@@ -0,0 +1,50 @@
+use crate::container::Container;
+use crate::store::Instance;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+type Decorator = Arc<dyn Fn(Instance, &Container) -> Instance + Send + Sync>;
+
+pub(crate) type Decorators = HashMap<TypeId, Vec<Decorator>>;
+
+impl Container {
+    /// Wraps whatever the binding of `T` builds, now or after a later rebind,
+    /// e.g. to layer caching or logging around another provider's service.
+    /// Decorators apply in the order they were added, innermost first.
+    pub fn decorate<T, F>(&self, decorator: F) -> &Self
+    where
+        T: Send + Sync + 'static,
+        F: Fn(Arc<T>, &Container) -> Arc<T> + Send + Sync + 'static,
+    {
+        let type_id = TypeId::of::<T>();
+        if self.sealed.lock().unwrap().contains(&type_id) {
+            panic!(
+                "Cannot decorate sealed type: {:?}",
+                std::any::type_name::<T>()
+            );
+        }
+
+        let decorator: Decorator =
+            Arc::new(move |inst, c| decorator(inst.downcast::<T>().unwrap(), c) as Instance);
+        self.decorators
+            .lock()
+            .unwrap()
+            .entry(type_id)
+            .or_default()
+            .push(decorator);
+        self.instances.remove(&type_id);
+        self
+    }
+
+    pub(crate) fn apply_decorators(&self, type_id: TypeId, built: Instance) -> Instance {
+        let decorators = match self.decorators.lock().unwrap().get(&type_id) {
+            Some(decorators) => decorators.clone(),
+            None => return built,
+        };
+
+        decorators
+            .iter()
+            .fold(built, |inst, decorator| decorator(inst, self))
+    }
+}
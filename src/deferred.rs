@@ -0,0 +1,204 @@
+use crate::container::{Container, ProviderEntry};
+use luminos_contracts::support::ServiceProvider;
+use std::any::TypeId;
+use std::sync::{Arc, Condvar, Mutex, PoisonError};
+use std::thread::{self, ThreadId};
+
+/// Metadata for providers that only need to run once one of their services
+/// is requested. Usually implemented through [`provides!`](crate::provides).
+pub trait Provides {
+    fn provides() -> Vec<(TypeId, &'static str)>;
+}
+
+/// Declares the services a deferred provider binds; `dyn Trait` entries
+/// stand for trait objects bound with `bind_trait`. The declarations are
+/// checked against the provider's bindings when it loads, not at compile time.
+///
+/// ```ignore
+/// provides!(CacheServiceProvider => RedisCache, dyn Cache);
+/// ```
+#[macro_export]
+macro_rules! provides {
+    (@acc [$($services:expr),*] ;) => {
+        vec![$($services),*]
+    };
+    (@acc [$($services:expr),*] ; dyn $tr:path $(, $($rest:tt)*)?) => {
+        $crate::provides!(
+            @acc [$($services,)* (
+                ::std::any::TypeId::of::<::std::sync::Arc<dyn $tr>>(),
+                ::std::any::type_name::<dyn $tr>(),
+            )] ; $($($rest)*)?
+        )
+    };
+    (@acc [$($services:expr),*] ; $service:ty $(, $($rest:tt)*)?) => {
+        $crate::provides!(
+            @acc [$($services,)* (
+                ::std::any::TypeId::of::<$service>(),
+                ::std::any::type_name::<$service>(),
+            )] ; $($($rest)*)?
+        )
+    };
+    ($provider:ty => $($services:tt)+) => {
+        impl $crate::Provides for $provider {
+            fn provides() -> Vec<(::std::any::TypeId, &'static str)> {
+                $crate::provides!(@acc [] ; $($services)+)
+            }
+        }
+    };
+}
+
+pub(crate) struct DeferredEntry {
+    provides: Vec<(TypeId, &'static str)>,
+    provider: Arc<ProviderSlot>,
+    loaded: bool,
+}
+
+/// Holds a deferred provider between loads. While one thread runs its
+/// `register`/`boot` the provider is checked out, and resolves of its
+/// services from other threads wait for it rather than finding nothing
+/// bound. The lock itself is only held to check it out and back in.
+struct ProviderSlot {
+    state: Mutex<SlotState>,
+    returned: Condvar,
+}
+
+struct SlotState {
+    provider: Option<ProviderEntry>,
+    loader: Option<ThreadId>,
+}
+
+/// Checks the provider back in when loading finishes or unwinds, so a
+/// failed load can be retried.
+struct Checkout<'a> {
+    slot: &'a ProviderSlot,
+    entry: Option<ProviderEntry>,
+}
+
+impl Drop for Checkout<'_> {
+    fn drop(&mut self) {
+        let mut state = self
+            .slot
+            .state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        state.provider = self.entry.take();
+        state.loader = None;
+        self.slot.returned.notify_all();
+    }
+}
+
+impl DeferredEntry {
+    /// An entry for another container sharing this one's provider slot,
+    /// unless it already loaded here and its bindings came along anyway.
+    /// Whichever container loads it first checks the provider back in
+    /// afterwards, so the other can still load it for itself.
+    pub(crate) fn share(&self) -> Option<Self> {
+        (!self.loaded).then(|| Self {
+            provides: self.provides.clone(),
+            provider: self.provider.clone(),
+            loaded: false,
+        })
+    }
+}
+
+impl Container {
+    /// Whether a deferred provider that has not loaded yet declares `type_id`.
+    pub(crate) fn is_deferred(&self, type_id: TypeId) -> bool {
+        self.deferred
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|d| !d.loaded && d.provides.iter().any(|(id, _)| *id == type_id))
+    }
+
+    /// Adds a provider whose `register`/`boot` run the first time one of
+    /// its declared services is resolved rather than during [`boot`].
+    ///
+    /// [`boot`]: luminos_contracts::container::Contract::boot
+    #[track_caller]
+    pub fn add_deferred_provider<P>(&self, provider: P) -> &Self
+    where
+        P: Provides + ServiceProvider<Container> + 'static,
+    {
+        self.deferred.lock().unwrap().push(DeferredEntry {
+            provides: P::provides(),
+            provider: Arc::new(ProviderSlot {
+                state: Mutex::new(SlotState {
+                    provider: Some(ProviderEntry::new(
+                        Some(std::any::type_name::<P>()),
+                        Box::new(provider),
+                    )),
+                    loader: None,
+                }),
+                returned: Condvar::new(),
+            }),
+            loaded: false,
+        });
+        self
+    }
+
+    /// Loads the deferred provider declaring `type_id`; returns whether one
+    /// ran. Returns false when the provider is already loading on this
+    /// thread, i.e. it resolved one of its own services during `register`.
+    pub(crate) fn load_deferred(&self, type_id: TypeId) -> bool {
+        let (index, slot) = {
+            let deferred = self.deferred.lock().unwrap();
+            let Some(index) = deferred
+                .iter()
+                .position(|d| !d.loaded && d.provides.iter().any(|(id, _)| *id == type_id))
+            else {
+                return false;
+            };
+            (index, deferred[index].provider.clone())
+        };
+
+        let entry = {
+            let current = thread::current().id();
+            let mut state = slot.state.lock().unwrap_or_else(PoisonError::into_inner);
+            loop {
+                match state.loader {
+                    Some(loader) if loader == current => return false,
+                    Some(_) => {
+                        state = slot
+                            .returned
+                            .wait(state)
+                            .unwrap_or_else(PoisonError::into_inner);
+                    }
+                    None => break,
+                }
+            }
+            if self.deferred.lock().unwrap()[index].loaded {
+                return true;
+            }
+            let Some(entry) = state.provider.take() else {
+                return false;
+            };
+            state.loader = Some(current);
+            entry
+        };
+        let mut checkout = Checkout {
+            slot: &slot,
+            entry: Some(entry),
+        };
+        let entry = checkout.entry.as_mut().unwrap();
+
+        self.register_provider(entry);
+        let provides = self.deferred.lock().unwrap()[index].provides.clone();
+        let missing: Vec<&str> = provides
+            .iter()
+            .filter(|(id, _)| !self.factories.lock().unwrap().contains_key(id))
+            .map(|(_, name)| *name)
+            .collect();
+        if !missing.is_empty() {
+            panic!(
+                "Deferred provider {:?} declares services it never binds: {}",
+                entry.name.unwrap_or_default(),
+                missing.join(", ")
+            );
+        }
+
+        self.deferred.lock().unwrap()[index].loaded = true;
+        self.boot_provider(entry);
+        true
+    }
+}
@@ -0,0 +1,65 @@
+use crate::container::Container;
+use crate::trace;
+use std::collections::{BTreeSet, HashMap};
+
+/// Consumer type name -> names of the services it resolved while being built.
+pub(crate) type DependencyEdges = HashMap<&'static str, BTreeSet<&'static str>>;
+
+impl Container {
+    /// Records `dependency` as used by whatever factory is running on this thread.
+    pub(crate) fn record_dependency(&self, dependency: &'static str) {
+        if let Some(consumer) = trace::current_resolving()
+            && consumer != dependency
+        {
+            self.dependencies
+                .lock()
+                .unwrap()
+                .entry(consumer)
+                .or_default()
+                .insert(dependency);
+        }
+    }
+
+    /// Declares edges up front, e.g. from `#[injectable]` constructor metadata,
+    /// so they are known before the consumer is ever built.
+    pub fn declare_dependencies(
+        &self,
+        consumer: &'static str,
+        dependencies: &[&'static str],
+    ) -> &Self {
+        self.dependencies
+            .lock()
+            .unwrap()
+            .entry(consumer)
+            .or_default()
+            .extend(dependencies.iter().copied());
+        self
+    }
+
+    /// Every consumer known to depend on `T`, directly or through other services.
+    pub fn dependents_of<T: ?Sized + 'static>(&self) -> Vec<&'static str> {
+        let edges = self.dependencies.lock().unwrap();
+        let mut found = BTreeSet::new();
+        let mut pending = vec![std::any::type_name::<T>()];
+
+        while let Some(target) = pending.pop() {
+            for (consumer, dependencies) in edges.iter() {
+                if dependencies.contains(target) && found.insert(*consumer) {
+                    pending.push(consumer);
+                }
+            }
+        }
+
+        found.into_iter().collect()
+    }
+
+    /// Direct dependencies of `T` that have been declared or observed.
+    pub fn dependencies_of<T: ?Sized + 'static>(&self) -> Vec<&'static str> {
+        self.dependencies
+            .lock()
+            .unwrap()
+            .get(std::any::type_name::<T>())
+            .map(|deps| deps.iter().copied().collect())
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,67 @@
+use crate::container::Container;
+use crate::scope::Scope;
+use serde::de::{DeserializeSeed, Deserializer};
+use std::marker::PhantomData;
+
+/// A type whose deserialization needs container services, e.g. a registry
+/// to intern ids. Services are resolved through `scope`.
+pub trait DeserializeInjected: Sized {
+    fn deserialize_injected<'de, D>(deserializer: D, scope: &Scope<'_>) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+}
+
+/// [`DeserializeSeed`] handing a scope to `T::deserialize_injected`:
+///
+/// ```ignore
+/// let order = scope
+///     .seed::<Order>()
+///     .deserialize(&mut serde_json::Deserializer::from_str(json))?;
+/// ```
+pub struct Injected<'s, 'c, T> {
+    source: SeedSource<'s, 'c>,
+    marker: PhantomData<fn() -> T>,
+}
+
+enum SeedSource<'s, 'c> {
+    Container(&'c Container),
+    Scope(&'s Scope<'c>),
+}
+
+impl<'de, T: DeserializeInjected> DeserializeSeed<'de> for Injected<'_, '_, T> {
+    type Value = T;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match self.source {
+            SeedSource::Scope(scope) => T::deserialize_injected(deserializer, scope),
+            SeedSource::Container(container) => {
+                let scope = container.scope();
+                T::deserialize_injected(deserializer, &scope)
+            }
+        }
+    }
+}
+
+impl Container {
+    /// Seed that deserializes `T` within a fresh scope of this container.
+    pub fn seed<T: DeserializeInjected>(&self) -> Injected<'_, '_, T> {
+        Injected {
+            source: SeedSource::Container(self),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'c> Scope<'c> {
+    /// Seed that deserializes `T` against this scope, so scoped services
+    /// and locals are visible.
+    pub fn seed<T: DeserializeInjected>(&self) -> Injected<'_, 'c, T> {
+        Injected {
+            source: SeedSource::Scope(self),
+            marker: PhantomData,
+        }
+    }
+}
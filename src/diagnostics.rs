@@ -0,0 +1,219 @@
+use crate::container::Container;
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+const HEADER: &str = "# luminos-container diagnostics v1";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProviderInfo {
+    pub name: String,
+    pub priority: i32,
+    pub location: String,
+    pub bindings: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BindingInfo {
+    pub type_name: String,
+    pub lifetime: String,
+    pub flags: Vec<String>,
+    pub location: String,
+    pub resolutions: usize,
+}
+
+/// Point-in-time export of a container's providers and bindings, written
+/// by [`Container::write_diagnostics`] and read back by `luminos-inspect`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Diagnostics {
+    pub providers: Vec<ProviderInfo>,
+    pub bindings: Vec<BindingInfo>,
+}
+
+impl Diagnostics {
+    pub fn capture(container: &Container) -> Self {
+        let factories = container.factories.lock().unwrap().clone();
+        let resolutions = container.resolutions.snapshot();
+        let sealed = container.sealed.lock().unwrap().clone();
+
+        let mut bindings: Vec<BindingInfo> = factories
+            .iter()
+            .map(|(type_id, binding)| {
+                let mut flags = Vec::new();
+                if binding.implicit {
+                    flags.push("implicit".to_string());
+                }
+                if sealed.contains(type_id) {
+                    flags.push("sealed".to_string());
+                }
+
+                BindingInfo {
+                    type_name: binding.type_name.to_string(),
+                    lifetime: binding.lifetime.to_string(),
+                    flags,
+                    location: binding.location.to_string(),
+                    resolutions: resolutions.get(type_id).copied().unwrap_or(0),
+                }
+            })
+            .collect();
+        bindings.sort_by(|a, b| a.type_name.cmp(&b.type_name));
+
+        let providers = container
+            .providers
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|entry| ProviderInfo {
+                name: entry.name.unwrap_or("<anonymous>").to_string(),
+                priority: entry.priority,
+                location: entry.location.to_string(),
+                bindings: entry
+                    .bindings
+                    .iter()
+                    .filter_map(|type_id| factories.get(type_id))
+                    .map(|binding| binding.type_name.to_string())
+                    .collect(),
+            })
+            .collect();
+
+        Self {
+            providers,
+            bindings,
+        }
+    }
+
+    /// Tab-separated, line-oriented export format.
+    pub fn export(&self) -> String {
+        let mut out = format!("{HEADER}\n");
+
+        for provider in &self.providers {
+            out.push_str(&format!(
+                "provider\t{}\t{}\t{}\n",
+                provider.name, provider.priority, provider.location
+            ));
+            for binding in &provider.bindings {
+                out.push_str(&format!("provides\t{}\t{}\n", provider.name, binding));
+            }
+        }
+
+        for binding in &self.bindings {
+            out.push_str(&format!(
+                "binding\t{}\t{}\t{}\t{}\t{}\n",
+                binding.type_name,
+                binding.lifetime,
+                binding.flags.join(","),
+                binding.location,
+                binding.resolutions
+            ));
+        }
+
+        out
+    }
+
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut lines = input.lines();
+        if lines.next().map(str::trim) != Some(HEADER) {
+            return Err("not a luminos-container diagnostics export".to_string());
+        }
+
+        let mut diagnostics = Self::default();
+
+        for (number, line) in lines.enumerate() {
+            let fields: Vec<&str> = line.split('\t').collect();
+            let bad = || format!("line {}: malformed {:?} record", number + 2, fields[0]);
+
+            match fields.as_slice() {
+                ["provider", name, priority, location] => {
+                    diagnostics.providers.push(ProviderInfo {
+                        name: name.to_string(),
+                        priority: priority.parse().map_err(|_| bad())?,
+                        location: location.to_string(),
+                        bindings: Vec::new(),
+                    });
+                }
+                ["provides", provider, binding] => diagnostics
+                    .providers
+                    .iter_mut()
+                    .rev()
+                    .find(|p| p.name == *provider)
+                    .ok_or_else(bad)?
+                    .bindings
+                    .push(binding.to_string()),
+                ["binding", type_name, lifetime, flags, location, resolutions] => {
+                    diagnostics.bindings.push(BindingInfo {
+                        type_name: type_name.to_string(),
+                        lifetime: lifetime.to_string(),
+                        flags: flags
+                            .split(',')
+                            .filter(|f| !f.is_empty())
+                            .map(str::to_string)
+                            .collect(),
+                        location: location.to_string(),
+                        resolutions: resolutions.parse().map_err(|_| bad())?,
+                    });
+                }
+                [""] => {}
+                _ => return Err(bad()),
+            }
+        }
+
+        Ok(diagnostics)
+    }
+}
+
+impl fmt::Display for Diagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Providers ({}):", self.providers.len())?;
+        for provider in &self.providers {
+            writeln!(
+                f,
+                "  {} (priority {}) at {}",
+                provider.name, provider.priority, provider.location
+            )?;
+        }
+
+        writeln!(f, "\nBindings ({}):", self.bindings.len())?;
+        let width = self
+            .bindings
+            .iter()
+            .map(|b| b.type_name.len())
+            .max()
+            .unwrap_or(0);
+        for binding in &self.bindings {
+            writeln!(
+                f,
+                "  {:width$}  {:9}  {:>6} resolutions  {}{}",
+                binding.type_name,
+                binding.lifetime,
+                binding.resolutions,
+                binding.location,
+                if binding.flags.is_empty() {
+                    String::new()
+                } else {
+                    format!("  [{}]", binding.flags.join(", "))
+                },
+            )?;
+        }
+
+        writeln!(f, "\nGraph:")?;
+        for provider in &self.providers {
+            writeln!(f, "  {}", provider.name)?;
+            for binding in &provider.bindings {
+                writeln!(f, "    -> {binding}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Container {
+    pub fn diagnostics(&self) -> Diagnostics {
+        Diagnostics::capture(self)
+    }
+
+    /// Writes a diagnostics export for `luminos-inspect` to `path`.
+    pub fn write_diagnostics(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        std::fs::write(path, self.diagnostics().export())
+    }
+}
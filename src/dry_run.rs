@@ -0,0 +1,165 @@
+use crate::captive::CaptiveDependency;
+use crate::container::Container;
+use crate::error::panic_message;
+use crate::lifetime::Lifetime;
+use crate::manifest::ManifestConflict;
+use crate::ordering;
+use crate::provider_state::{ProviderStateGuard, StateSlots};
+use crate::try_boot::{BootPhase, ProviderFailure, describe};
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+
+/// A binding one provider replaced after another had registered it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rebinding {
+    pub type_name: &'static str,
+    pub provider: String,
+}
+
+/// What [`Container::dry_run_boot`] found, without anything having booted.
+#[derive(Debug, Clone, Default)]
+pub struct DryRunReport {
+    /// Type names each provider would bind, in registration order.
+    pub bindings_added: Vec<(String, Vec<&'static str>)>,
+    pub rebound: Vec<Rebinding>,
+    pub conflicts: Vec<ManifestConflict>,
+    pub failures: Vec<ProviderFailure>,
+    /// Singletons that could not be built, with the reason.
+    pub unresolvable: Vec<(&'static str, String)>,
+    pub captive: Vec<CaptiveDependency>,
+    /// Provider ordering or options validation errors.
+    pub errors: Vec<String>,
+}
+
+impl DryRunReport {
+    /// Whether `boot` would be expected to succeed.
+    pub fn is_ok(&self) -> bool {
+        self.conflicts.is_empty()
+            && self.failures.is_empty()
+            && self.unresolvable.is_empty()
+            && self.captive.is_empty()
+            && self.errors.is_empty()
+    }
+}
+
+impl fmt::Display for DryRunReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (provider, bindings) in &self.bindings_added {
+            writeln!(f, "{provider}:")?;
+            for binding in bindings {
+                writeln!(f, "  + {binding}")?;
+            }
+        }
+        for rebinding in &self.rebound {
+            writeln!(
+                f,
+                "rebound: {} by {}",
+                rebinding.type_name, rebinding.provider
+            )?;
+        }
+        for conflict in &self.conflicts {
+            writeln!(f, "conflict: {conflict}")?;
+        }
+        for failure in &self.failures {
+            writeln!(
+                f,
+                "failed: {} during {}: {}",
+                failure.provider, failure.phase, failure.message
+            )?;
+        }
+        for (type_name, reason) in &self.unresolvable {
+            writeln!(f, "unresolvable: {type_name}: {reason}")?;
+        }
+        for captive in &self.captive {
+            writeln!(f, "captive: {captive}")?;
+        }
+        for error in &self.errors {
+            writeln!(f, "error: {error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Container {
+    /// Registers every pending provider into a staging copy of this
+    /// container and verifies the result, reporting what `boot` would do.
+    /// No provider's `boot` runs and this container is left untouched,
+    /// though singleton factories run inside the staging copy.
+    pub fn dry_run_boot(&self) -> DryRunReport {
+        let mut report = DryRunReport::default();
+        if let Err(conflicts) = self.check_manifests() {
+            report.conflicts = conflicts;
+        }
+
+        let staging = self.clone_cow();
+
+        let mut providers = self.checkout_providers();
+        providers.sort_by_key(|entry| std::cmp::Reverse(entry.priority));
+        if let Err(err) = ordering::order_providers(&mut providers) {
+            report.errors.push(err.to_string());
+        }
+
+        for entry in providers.iter() {
+            let before = staging.factories.lock().unwrap().clone();
+            let registered = panic::catch_unwind(AssertUnwindSafe(|| {
+                // A throwaway slot, so state set up here is not what the
+                // real `register` and `boot` find later.
+                let _state = ProviderStateGuard::enter(StateSlots::default());
+                entry.provider.register(&staging);
+            }));
+            if let Err(payload) = registered {
+                report.failures.push(ProviderFailure {
+                    provider: describe(entry),
+                    phase: BootPhase::Register,
+                    message: panic_message(&*payload),
+                });
+            }
+
+            let after = staging.factories.lock().unwrap().clone();
+            let mut added = Vec::new();
+            for (type_id, binding) in after.iter() {
+                match before.get(type_id) {
+                    None => added.push(binding.type_name),
+                    Some(previous) if !Arc::ptr_eq(&previous.factory, &binding.factory) => {
+                        report.rebound.push(Rebinding {
+                            type_name: binding.type_name,
+                            provider: describe(entry),
+                        })
+                    }
+                    Some(_) => {}
+                }
+            }
+            added.sort_unstable();
+            report.bindings_added.push((describe(entry), added));
+        }
+        drop(providers);
+
+        if let Err(errors) = staging.validate_options() {
+            report.errors.push(errors.to_string());
+        }
+
+        let singletons: Vec<_> = staging
+            .factories
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, binding)| !binding.implicit && binding.lifetime == Lifetime::Singleton)
+            .map(|(type_id, binding)| (*type_id, binding.type_name))
+            .collect();
+        staging.scope().enter(|_| {
+            for (type_id, type_name) in singletons {
+                if let Err(payload) =
+                    panic::catch_unwind(AssertUnwindSafe(|| staging.resolve_instance(type_id)))
+                {
+                    report
+                        .unresolvable
+                        .push((type_name, panic_message(&*payload)));
+                }
+            }
+        });
+        report.captive = staging.captive_dependencies();
+
+        report
+    }
+}
@@ -0,0 +1,94 @@
+use crate::container::Container;
+use luminos_contracts::container::{Contract, Injectable};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Snapshot of the process environment, injected instead of reading
+/// `std::env` directly so environment-dependent services stay testable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Environment {
+    name: String,
+    vars: HashMap<String, String>,
+}
+
+impl Environment {
+    /// The variable holding the environment name.
+    pub const NAME_VAR: &'static str = "APP_ENV";
+
+    pub fn from_process() -> Self {
+        Self::from_vars(std::env::vars().collect())
+    }
+
+    /// Builds an environment from explicit variables; the name comes from
+    /// `APP_ENV` and defaults to `production`.
+    pub fn from_vars(vars: HashMap<String, String>) -> Self {
+        let name = vars
+            .get(Self::NAME_VAR)
+            .cloned()
+            .unwrap_or_else(|| "production".to_string());
+
+        Self { name, vars }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn is(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+    }
+
+    pub fn is_production(&self) -> bool {
+        self.is("production") || self.is("prod")
+    }
+
+    pub fn is_development(&self) -> bool {
+        self.is("development") || self.is("dev") || self.is("local")
+    }
+
+    pub fn is_testing(&self) -> bool {
+        self.is("testing") || self.is("test")
+    }
+
+    pub fn raw(&self, key: &str) -> Option<&str> {
+        self.vars.get(key).map(String::as_str)
+    }
+
+    /// Parses `key` into `T`; `None` if it is unset or does not parse.
+    pub fn get<T: FromStr>(&self, key: &str) -> Option<T> {
+        self.raw(key)?.trim().parse().ok()
+    }
+
+    pub fn get_or<T: FromStr>(&self, key: &str, default: T) -> T {
+        self.get(key).unwrap_or(default)
+    }
+
+    /// Like [`Environment::get`] for booleans, also accepting `1`/`0`,
+    /// `yes`/`no` and `on`/`off`.
+    pub fn flag(&self, key: &str) -> Option<bool> {
+        match self.raw(key)?.trim().to_ascii_lowercase().as_str() {
+            "1" | "true" | "yes" | "on" => Some(true),
+            "0" | "false" | "no" | "off" | "" => Some(false),
+            _ => None,
+        }
+    }
+}
+
+impl Injectable for Environment {
+    fn __register<C: Contract>(container: &C) {
+        container.bind::<Environment, _>(|_| Arc::new(Environment::from_process()));
+    }
+}
+
+impl Container {
+    #[track_caller]
+    pub fn set_environment(&self, environment: Environment) -> &Self {
+        self.instance(Arc::new(environment))
+    }
+
+    /// The [`Environment`], captured from the process on first use or at boot.
+    pub fn environment(&self) -> Arc<Environment> {
+        self.resolve::<Environment>()
+    }
+}
@@ -0,0 +1,158 @@
+use crate::container::Container;
+use luminos_contracts::container::Injectable;
+use std::any::Any;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe, Location};
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContainerError {
+    /// No binding, no `Injectable` registration and no `on_missing` answer.
+    MissingBinding { type_name: &'static str },
+    /// The stored instance was not of the requested type.
+    DowncastFailed {
+        type_name: &'static str,
+        bound_at: Option<&'static Location<'static>>,
+    },
+    /// A factory panicked while building the service or one of its dependencies.
+    FactoryPanicked {
+        type_name: &'static str,
+        message: String,
+        bound_at: Option<&'static Location<'static>>,
+    },
+    /// The type was sealed and cannot be rebound.
+    Sealed {
+        type_name: &'static str,
+        bound_at: Option<&'static Location<'static>>,
+    },
+    /// The container was frozen and accepts no more bindings.
+    Frozen { type_name: &'static str },
+    /// Saving the service's persistent state failed during shutdown.
+    PersistFailed {
+        type_name: &'static str,
+        message: String,
+    },
+    /// A chaos rule failed the construction of this service or one of its
+    /// dependencies; `type_name` is the service the rule targeted.
+    FaultInjected { type_name: &'static str },
+}
+
+impl ContainerError {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::MissingBinding { type_name }
+            | Self::DowncastFailed { type_name, .. }
+            | Self::FactoryPanicked { type_name, .. }
+            | Self::Sealed { type_name, .. }
+            | Self::Frozen { type_name }
+            | Self::PersistFailed { type_name, .. }
+            | Self::FaultInjected { type_name } => type_name,
+        }
+    }
+}
+
+impl fmt::Display for ContainerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingBinding { type_name } => {
+                write!(f, "Failed to resolve type: {type_name:?}")
+            }
+            Self::DowncastFailed {
+                type_name,
+                bound_at,
+            } => {
+                write!(
+                    f,
+                    "Failed to resolve type: {type_name:?} (instance has a different type)"
+                )?;
+                write_bound_at(f, *bound_at)
+            }
+            Self::FactoryPanicked {
+                type_name,
+                message,
+                bound_at,
+            } => {
+                write!(
+                    f,
+                    "Failed to resolve type: {type_name:?} (factory panicked: {message})"
+                )?;
+                write_bound_at(f, *bound_at)
+            }
+            Self::Sealed {
+                type_name,
+                bound_at,
+            } => {
+                write!(f, "Cannot rebind sealed type: {type_name:?}")?;
+                write_bound_at(f, *bound_at)
+            }
+            Self::Frozen { type_name } => {
+                write!(f, "Cannot bind type: {type_name:?} (container is frozen)")
+            }
+            Self::PersistFailed { type_name, message } => {
+                write!(
+                    f,
+                    "Failed to save persistent state: {type_name:?} ({message})"
+                )
+            }
+            Self::FaultInjected { type_name } => {
+                write!(
+                    f,
+                    "Failed to resolve type: {type_name:?} (chaos fault injected)"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ContainerError {}
+
+fn write_bound_at(
+    f: &mut fmt::Formatter<'_>,
+    bound_at: Option<&'static Location<'static>>,
+) -> fmt::Result {
+    match bound_at {
+        Some(location) => write!(f, " (bound at {location})"),
+        None => Ok(()),
+    }
+}
+
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .or_else(|| injected_fault(payload).map(|error| error.to_string()))
+        .unwrap_or_else(|| "non-string panic payload".to_string())
+}
+
+/// Maps a panic raised by an injected chaos fault to its own error.
+fn injected_fault(_payload: &(dyn Any + Send)) -> Option<ContainerError> {
+    #[cfg(feature = "chaos")]
+    if let Some(fault) = _payload.downcast_ref::<crate::chaos::ChaosFault>() {
+        return Some(ContainerError::FaultInjected {
+            type_name: fault.service,
+        });
+    }
+    None
+}
+
+impl Container {
+    /// Like `resolve`, but reports failures instead of panicking.
+    pub fn try_resolve<T>(&self) -> Result<Arc<T>, ContainerError>
+    where
+        T: Injectable + Send + Sync + 'static,
+    {
+        panic::catch_unwind(AssertUnwindSafe(|| self.resolve_checked::<T>())).unwrap_or_else(
+            |payload| {
+                if let Some(error) = injected_fault(payload.as_ref()) {
+                    return Err(error);
+                }
+                Err(ContainerError::FactoryPanicked {
+                    type_name: std::any::type_name::<T>(),
+                    message: panic_message(payload.as_ref()),
+                    bound_at: self.binding_location::<T>(),
+                })
+            },
+        )
+    }
+}
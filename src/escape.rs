@@ -0,0 +1,116 @@
+use crate::container::Container;
+use crate::store::{InstanceStore, MemoryStore};
+use crate::trace;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// A scoped instance still referenced after its scope was dropped, usually
+/// because a transient consumer built in the scope was kept beyond it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScopeEscape {
+    pub scope: u64,
+    pub service: &'static str,
+    /// Types that were being built when the instance was handed out;
+    /// `<caller>` marks a direct resolution from the scope.
+    pub consumers: Vec<&'static str>,
+}
+
+pub(crate) struct EscapeAudit {
+    enabled: AtomicBool,
+    handouts: Mutex<HashMap<u64, Vec<(TypeId, &'static str)>>>,
+    reports: Mutex<Vec<ScopeEscape>>,
+}
+
+impl Default for EscapeAudit {
+    fn default() -> Self {
+        Self {
+            enabled: AtomicBool::new(false),
+            handouts: Mutex::new(HashMap::new()),
+            reports: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl Container {
+    /// Turns escape tracking for scoped instances on or off; it is off by
+    /// default. Escapes are collected for [`Container::scope_escapes`] and,
+    /// with the `tracing` feature, emitted as warnings.
+    pub fn audit_scope_escapes(&self, enabled: bool) -> &Self {
+        self.escape_audit.enabled.store(enabled, Ordering::Relaxed);
+        self
+    }
+
+    /// Escapes detected so far, oldest first.
+    pub fn scope_escapes(&self) -> Vec<ScopeEscape> {
+        self.escape_audit.reports.lock().unwrap().clone()
+    }
+
+    pub(crate) fn record_scoped_handout(&self, type_id: TypeId) {
+        if !self.escape_audit.enabled.load(Ordering::Relaxed) {
+            return;
+        }
+        let Some(scope) = trace::current_scope() else {
+            return;
+        };
+
+        let consumer = trace::current_resolving().unwrap_or("<caller>");
+        self.escape_audit
+            .handouts
+            .lock()
+            .unwrap()
+            .entry(scope)
+            .or_default()
+            .push((type_id, consumer));
+    }
+
+    /// Empties the scope's store, then reports every scoped instance that
+    /// something outside the scope still keeps alive.
+    pub(crate) fn check_scope_escapes(&self, scope: u64, store: &MemoryStore) {
+        let Some(handouts) = self.escape_audit.handouts.lock().unwrap().remove(&scope) else {
+            return;
+        };
+
+        let mut consumers: HashMap<TypeId, Vec<&'static str>> = HashMap::new();
+        for (type_id, consumer) in handouts {
+            let list = consumers.entry(type_id).or_default();
+            if !list.contains(&consumer) {
+                list.push(consumer);
+            }
+        }
+
+        let tracked: Vec<_> = consumers
+            .keys()
+            .filter_map(|type_id| Some((*type_id, Arc::downgrade(&store.get(type_id)?))))
+            .collect();
+        store.clear();
+
+        for (type_id, weak) in tracked {
+            if weak.strong_count() == 0 {
+                continue;
+            }
+
+            let service = self
+                .factories
+                .lock()
+                .unwrap()
+                .get(&type_id)
+                .map_or("<unknown>", |binding| binding.type_name);
+            let escape = ScopeEscape {
+                scope,
+                service,
+                consumers: consumers.remove(&type_id).unwrap_or_default(),
+            };
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                target: "luminos_container",
+                service = escape.service,
+                scope = escape.scope,
+                consumers = %escape.consumers.join(", "),
+                "scoped service outlived its scope"
+            );
+            self.escape_audit.reports.lock().unwrap().push(escape);
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::error::ContainerError;
+use crate::try_contract::TryContract;
+use luminos_contracts::container::{Contract, Injectable};
+use std::sync::Arc;
+
+/// A closure whose `Arc<T>` arguments can all be resolved from `C`.
+pub trait InjectedFn<C, Args, R> {
+    fn call_with(self, container: &C) -> R;
+}
+
+macro_rules! impl_injected_fn {
+    ($($arg:ident),*) => {
+        impl<C, F, R, $($arg),*> InjectedFn<C, ($(Arc<$arg>,)*), R> for F
+        where
+            C: Contract,
+            F: FnOnce($(Arc<$arg>),*) -> R,
+            $($arg: Injectable + Send + Sync + 'static,)*
+        {
+            #[allow(unused_variables)]
+            fn call_with(self, container: &C) -> R {
+                self($(container.resolve::<$arg>()),*)
+            }
+        }
+    };
+}
+
+impl_injected_fn!();
+impl_injected_fn!(A1);
+impl_injected_fn!(A1, A2);
+impl_injected_fn!(A1, A2, A3);
+impl_injected_fn!(A1, A2, A3, A4);
+impl_injected_fn!(A1, A2, A3, A4, A5);
+impl_injected_fn!(A1, A2, A3, A4, A5, A6);
+
+/// Call-site sugar available on every [`Contract`] implementation.
+pub trait ContractExt: Contract {
+    fn get<T>(&self) -> Arc<T>
+    where
+        T: Injectable + Send + Sync + 'static,
+    {
+        self.resolve::<T>()
+    }
+
+    /// Resolves `T`, falling back to `default` if nothing provides it.
+    /// Any other failure, such as a panicking factory, still panics.
+    fn get_or<T>(&self, default: Arc<T>) -> Arc<T>
+    where
+        Self: TryContract,
+        T: Injectable + Send + Sync + 'static,
+    {
+        match self.try_resolve::<T>() {
+            Ok(inst) => inst,
+            Err(ContainerError::MissingBinding { .. }) => default,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    /// Calls `f` with each of its `Arc<T>` parameters resolved.
+    fn inject_into<Args, R, F>(&self, f: F) -> R
+    where
+        F: InjectedFn<Self, Args, R>,
+    {
+        f.call_with(self)
+    }
+}
+
+impl<C: Contract> ContractExt for C {}
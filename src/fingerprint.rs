@@ -0,0 +1,84 @@
+use crate::container::Container;
+
+impl Container {
+    /// A deterministic, line-per-entry description of the wiring: every
+    /// binding with its attributes, every named, multi, tagged and
+    /// contextual binding, and every provider in boot order.
+    /// Suitable for snapshot tests.
+    pub fn graph_snapshot(&self) -> String {
+        let mut bindings: Vec<String> = {
+            let factories = self.factories.lock().unwrap();
+            let sealed = self.sealed.lock().unwrap();
+            let bulkheads = self.bulkheads.lock().unwrap();
+
+            factories
+                .iter()
+                .map(|(type_id, binding)| {
+                    let mut line = format!("binding {} {}", binding.type_name, binding.lifetime);
+                    if binding.implicit {
+                        line.push_str(" implicit");
+                    }
+                    if sealed.contains(type_id) {
+                        line.push_str(" sealed");
+                    }
+                    if bulkheads.contains_key(type_id) {
+                        line.push_str(" bulkhead");
+                    }
+                    line
+                })
+                .collect()
+        };
+        for entry in self.named.lock().unwrap().values() {
+            bindings.extend(
+                entry
+                    .names()
+                    .map(|name| format!("named {} {name}", entry.type_name)),
+            );
+        }
+        for entry in self.multi.lock().unwrap().values() {
+            bindings.push(format!("multi {} count={}", entry.type_name, entry.len()));
+        }
+        for (tag, services) in self.tags.lock().unwrap().iter() {
+            bindings.extend(
+                services
+                    .iter()
+                    .map(|service| format!("tag {tag} {}", service.type_name())),
+            );
+        }
+        for ((consumer, _), (needed, _)) in self.contextual.lock().unwrap().iter() {
+            bindings.push(format!("contextual {consumer} needs {needed}"));
+        }
+        bindings.sort();
+
+        let providers = self
+            .providers
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|entry| {
+                format!(
+                    "provider {} priority={}",
+                    entry.name.unwrap_or("<anonymous>"),
+                    entry.priority
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut snapshot = String::new();
+        for line in bindings.iter().chain(providers.iter()) {
+            snapshot.push_str(line);
+            snapshot.push('\n');
+        }
+        snapshot
+    }
+
+    /// FNV-1a hash of [`Container::graph_snapshot`], stable across runs and
+    /// compiler versions.
+    pub fn graph_fingerprint(&self) -> u64 {
+        self.graph_snapshot()
+            .bytes()
+            .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+            })
+    }
+}
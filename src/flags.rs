@@ -0,0 +1,172 @@
+use crate::container::Container;
+use crate::invoke::Inject;
+use crate::scope::Scope;
+use crate::store::InstanceStore;
+use crate::trace;
+use luminos_contracts::container::{Contract, Injectable};
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::{Arc, RwLock};
+
+/// A feature toggle known at compile time:
+///
+/// ```ignore
+/// struct NewCheckout;
+/// impl Feature for NewCheckout {
+///     const NAME: &'static str = "new-checkout";
+/// }
+/// ```
+pub trait Feature: 'static {
+    const NAME: &'static str;
+    const DEFAULT: bool = false;
+}
+
+/// Container-wide toggle values, keyed by feature name.
+#[derive(Debug, Default)]
+pub struct FeatureFlags {
+    flags: RwLock<HashMap<String, bool>>,
+}
+
+impl FeatureFlags {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&self, name: &str, enabled: bool) -> &Self {
+        self.flags
+            .write()
+            .unwrap()
+            .insert(name.to_string(), enabled);
+        self
+    }
+
+    pub fn enable<F: Feature>(&self) -> &Self {
+        self.set(F::NAME, true)
+    }
+
+    pub fn disable<F: Feature>(&self) -> &Self {
+        self.set(F::NAME, false)
+    }
+
+    /// The configured value, or `None` to fall back to the feature's default.
+    pub fn get(&self, name: &str) -> Option<bool> {
+        self.flags.read().unwrap().get(name).copied()
+    }
+
+    pub fn is_enabled<F: Feature>(&self) -> bool {
+        self.get(F::NAME).unwrap_or(F::DEFAULT)
+    }
+}
+
+impl Injectable for FeatureFlags {
+    fn __register<C: Contract>(container: &C) {
+        container.bind::<FeatureFlags, _>(|_| Arc::new(FeatureFlags::new()));
+    }
+}
+
+/// Overrides set on one [`Scope`], consulted before [`FeatureFlags`].
+#[derive(Default)]
+struct ScopedFlags(FeatureFlags);
+
+/// Whether feature `F` is on for the current resolution. Obtain one with
+/// [`Container::flag`], [`Scope::flag`] or as an injected handler argument.
+pub struct Flag<F: Feature> {
+    enabled: bool,
+    _feature: PhantomData<fn() -> F>,
+}
+
+impl<F: Feature> Flag<F> {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn name(&self) -> &'static str {
+        F::NAME
+    }
+}
+
+impl<F: Feature> Clone for Flag<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: Feature> Copy for Flag<F> {}
+
+impl<F: Feature> fmt::Debug for Flag<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Flag")
+            .field("name", &F::NAME)
+            .field("enabled", &self.enabled)
+            .finish()
+    }
+}
+
+impl<F: Feature> Inject for Flag<F> {
+    fn inject(container: &Container) -> Self {
+        container.flag::<F>()
+    }
+}
+
+impl Container {
+    pub fn feature_flags(&self) -> Arc<FeatureFlags> {
+        self.resolve::<FeatureFlags>()
+    }
+
+    /// Reads `F`, preferring an override on the active scope.
+    pub fn flag<F: Feature>(&self) -> Flag<F> {
+        let scoped = trace::current_scope_store()
+            .and_then(|store| store.get(&TypeId::of::<ScopedFlags>()))
+            .and_then(|inst| inst.downcast::<ScopedFlags>().ok())
+            .and_then(|flags| flags.0.get(F::NAME));
+
+        Flag {
+            enabled: scoped.unwrap_or_else(|| self.feature_flags().is_enabled::<F>()),
+            _feature: PhantomData,
+        }
+    }
+}
+
+impl Scope<'_> {
+    /// Overrides a feature for this scope only.
+    pub fn set_flag(&self, name: &str, enabled: bool) -> &Self {
+        match self.local::<ScopedFlags>() {
+            Some(flags) => {
+                flags.0.set(name, enabled);
+            }
+            None => {
+                let flags = ScopedFlags::default();
+                flags.0.set(name, enabled);
+                self.insert_local(flags);
+            }
+        }
+        self
+    }
+
+    pub fn enable_flag<F: Feature>(&self) -> &Self {
+        self.set_flag(F::NAME, true)
+    }
+
+    pub fn disable_flag<F: Feature>(&self) -> &Self {
+        self.set_flag(F::NAME, false)
+    }
+
+    /// Applies a comma-separated override list such as a request header
+    /// value: `new-checkout, -beta-search` enables the first and disables
+    /// the second.
+    pub fn set_flags_from_header(&self, value: &str) -> &Self {
+        for name in value.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+            match name.strip_prefix('-') {
+                Some(name) => self.set_flag(name.trim(), false),
+                None => self.set_flag(name.strip_prefix('+').unwrap_or(name), true),
+            };
+        }
+        self
+    }
+
+    pub fn flag<F: Feature>(&self) -> Flag<F> {
+        self.enter(|scope| scope.container().flag::<F>())
+    }
+}
@@ -0,0 +1,79 @@
+use crate::container::Container;
+use std::any::TypeId;
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+
+impl Container {
+    /// Drops the cached instance of `T`; the next resolution rebuilds it from
+    /// the same binding. Returns whether anything was cached.
+    pub fn forget_instance<T>(&self) -> bool
+    where
+        T: Send + Sync + 'static,
+    {
+        self.instances.remove(&TypeId::of::<T>()).is_some()
+    }
+
+    /// Drops every cached service instance while keeping all bindings.
+    /// Container-owned state such as the secret store is unaffected.
+    pub fn forget_instances(&self) -> &Self {
+        self.instances.clear();
+        self
+    }
+
+    /// Removes the binding of `T` together with its cached instance.
+    pub fn forget_binding<T>(&self) -> bool
+    where
+        T: Send + Sync + 'static,
+    {
+        let type_id = TypeId::of::<T>();
+        if self.frozen.load(Ordering::SeqCst) {
+            panic!(
+                "Cannot forget type: {:?} (container is frozen)",
+                std::any::type_name::<T>()
+            );
+        }
+        if self.sealed.lock().unwrap().contains(&type_id) {
+            panic!(
+                "Cannot forget sealed type: {:?}",
+                std::any::type_name::<T>()
+            );
+        }
+
+        self.instances.remove(&type_id);
+        if let Some(pool) = self.warm_pools.write().unwrap().remove(&type_id) {
+            pool.retire();
+        }
+        Arc::make_mut(&mut *self.factories.lock().unwrap())
+            .remove(&type_id)
+            .is_some()
+    }
+
+    /// Forgets every binding and cached instance, sealed ones included:
+    /// plain, local, named, tagged, multi, versioned, value and contextual
+    /// bindings, decorators, resolving callbacks and warm pools.
+    ///
+    /// Providers, options layers and validators, lifetime overrides,
+    /// bulkheads, scope hooks, the missing handler and container-owned state
+    /// (environment, secrets, config, chaos rules) are kept.
+    pub fn flush(&self) -> &Self {
+        if self.frozen.load(Ordering::SeqCst) {
+            panic!("Cannot flush bindings: container is frozen");
+        }
+        *self.factories.lock().unwrap() = Arc::default();
+        self.local_factories.lock().unwrap().clear();
+        self.instances.clear();
+        self.sealed.lock().unwrap().clear();
+        self.named.lock().unwrap().clear();
+        self.multi.lock().unwrap().clear();
+        self.versions.lock().unwrap().clear();
+        self.values.write().unwrap().clear();
+        self.tags.lock().unwrap().clear();
+        self.contextual.lock().unwrap().clear();
+        self.decorators.lock().unwrap().clear();
+        *self.resolving_callbacks.write().unwrap() = Default::default();
+        for (_, pool) in self.warm_pools.write().unwrap().drain() {
+            pool.retire();
+        }
+        self
+    }
+}
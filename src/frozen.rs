@@ -0,0 +1,166 @@
+use crate::container::Container;
+use crate::error::ContainerError;
+use crate::lifetime::Lifetime;
+use crate::options::Options;
+use crate::scope::Scope;
+use crate::store::Instance;
+use crate::trace;
+use luminos_contracts::container::{Contract, Injectable};
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+
+/// A booted container that no longer accepts bindings. Every explicit
+/// singleton is built up front and served from a plain map without locks;
+/// other lifetimes fall back to the underlying container.
+///
+/// Only the resolving side of the container is exposed, so nothing can be
+/// bound, decorated or flushed while frozen; [`into_inner`](Self::into_inner)
+/// hands the container back unfrozen.
+pub struct FrozenContainer {
+    container: Container,
+    singletons: HashMap<TypeId, Instance>,
+    /// No contextual bindings or global resolved callbacks existed at
+    /// freeze time, so outside a scope the map is authoritative.
+    fast_path: bool,
+}
+
+impl FrozenContainer {
+    fn frozen_singleton<T>(&self) -> Option<Arc<T>>
+    where
+        T: Send + Sync + 'static,
+    {
+        if !self.fast_path || trace::current_scope_store().is_some() {
+            return None;
+        }
+        self.singletons
+            .get(&TypeId::of::<T>())
+            .and_then(|inst| inst.clone().downcast::<T>().ok())
+    }
+
+    pub fn get<T>(&self) -> Option<Arc<T>>
+    where
+        T: Send + Sync + 'static,
+    {
+        self.frozen_singleton::<T>()
+            .or_else(|| self.container.resolve_optional::<T>())
+    }
+
+    pub fn resolve<T>(&self) -> Arc<T>
+    where
+        T: Injectable + Send + Sync + 'static,
+    {
+        self.frozen_singleton::<T>()
+            .unwrap_or_else(|| self.container.resolve::<T>())
+    }
+
+    pub fn try_resolve<T>(&self) -> Result<Arc<T>, ContainerError>
+    where
+        T: Injectable + Send + Sync + 'static,
+    {
+        match self.frozen_singleton::<T>() {
+            Some(inst) => Ok(inst),
+            None => self.container.try_resolve::<T>(),
+        }
+    }
+
+    pub fn get_trait<Tr>(&self) -> Option<Arc<Tr>>
+    where
+        Tr: ?Sized + Send + Sync + 'static,
+    {
+        self.container.get_trait::<Tr>()
+    }
+
+    pub fn resolve_trait<Tr>(&self) -> Arc<Tr>
+    where
+        Tr: ?Sized + Send + Sync + 'static,
+    {
+        self.container.resolve_trait::<Tr>()
+    }
+
+    pub fn resolve_named<T>(&self, name: &str) -> Arc<T>
+    where
+        T: Send + Sync + 'static,
+    {
+        self.container.resolve_named::<T>(name)
+    }
+
+    pub fn resolve_all<T>(&self) -> Vec<Arc<T>>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.container.resolve_all::<T>()
+    }
+
+    pub fn resolve_tagged_as<Tr>(&self, tag: &str) -> Vec<Arc<Tr>>
+    where
+        Tr: ?Sized + Send + Sync + 'static,
+    {
+        self.container.resolve_tagged_as::<Tr>(tag)
+    }
+
+    pub fn resolve_versioned<T>(&self) -> Arc<T>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.container.resolve_versioned::<T>()
+    }
+
+    pub fn get_value<T>(&self) -> Option<T>
+    where
+        T: Copy + Send + Sync + 'static,
+    {
+        self.container.get_value::<T>()
+    }
+
+    pub fn options<T>(&self) -> Arc<Options<T>>
+    where
+        T: Default + Send + Sync + 'static,
+    {
+        self.container.options::<T>()
+    }
+
+    pub fn scope(&self) -> Scope<'_> {
+        self.container.scope()
+    }
+
+    /// Unfreezes the container and gives it back, e.g. for teardown.
+    pub fn into_inner(self) -> Container {
+        self.container.frozen.store(false, Ordering::SeqCst);
+        self.container
+    }
+}
+
+impl Container {
+    /// Builds every explicitly bound singleton and turns the container
+    /// read-only: any later `bind` panics. Call after `boot`.
+    pub fn freeze(self) -> FrozenContainer {
+        let singletons: Vec<TypeId> = self
+            .factories
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, binding)| !binding.implicit && binding.lifetime == Lifetime::Singleton)
+            .map(|(type_id, _)| *type_id)
+            .collect();
+        for type_id in singletons {
+            self.resolve_instance(type_id);
+        }
+
+        self.frozen.store(true, Ordering::SeqCst);
+        let singletons = self.instances.snapshot().into_iter().collect();
+        let fast_path = self.contextual.lock().unwrap().is_empty()
+            && self.resolving_callbacks.read().unwrap().global.is_empty();
+
+        FrozenContainer {
+            container: self,
+            singletons,
+            fast_path,
+        }
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen.load(Ordering::SeqCst)
+    }
+}
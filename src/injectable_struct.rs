@@ -0,0 +1,99 @@
+/// Declares a struct whose fields are all `Arc<Dependency>` and makes it
+/// injectable: a `new` taking every field in order is generated, and the
+/// registration resolves each dependency from the container.
+///
+/// ```ignore
+/// injectable_struct! {
+///     pub struct CheckoutService {
+///         orders: Arc<OrderRepository>,
+///         mailer: Arc<Mailer>,
+///     }
+/// }
+///
+/// let checkout = container.resolve::<CheckoutService>();
+/// ```
+///
+/// Listing trait objects after `as` also binds the struct as each of them,
+/// sharing the struct's own instance, once it is registered:
+///
+/// ```ignore
+/// injectable_struct! {
+///     pub struct ConsoleLogger as dyn Logger, dyn Sink {
+///         clock: Arc<Clock>,
+///     }
+/// }
+///
+/// container.register::<ConsoleLogger>();
+/// let logger = container.resolve_trait::<dyn Logger>();
+/// ```
+///
+/// This is the struct-level counterpart of `#[injectable]`, which still
+/// requires an `impl` block with a `new` constructor.
+#[macro_export]
+macro_rules! injectable_struct {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident $(as $($iface:ty),+)? {
+            $($(#[$field_meta:meta])* $field_vis:vis $field:ident : Arc<$dep:ty>),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $($(#[$field_meta])* $field_vis $field: ::std::sync::Arc<$dep>,)*
+        }
+
+        impl $name {
+            #[allow(clippy::too_many_arguments)]
+            $vis fn new($($field: ::std::sync::Arc<$dep>),*) -> Self {
+                Self { $($field),* }
+            }
+        }
+
+        impl $crate::Injectable for $name {
+            fn __register<C: $crate::__private::Contract>(container: &C) {
+                container.bind::<$name, _>(|c| {
+                    ::std::sync::Arc::new($name::new(
+                        $(<C as $crate::__private::Contract>::resolve::<$dep>(c)),*
+                    ))
+                });
+                $($(
+                    container.bind::<::std::sync::Arc<$iface>, _>(|c| {
+                        let inst: ::std::sync::Arc<$iface> =
+                            <C as $crate::__private::Contract>::resolve::<$name>(c);
+                        ::std::sync::Arc::new(inst)
+                    });
+                )+)?
+            }
+        }
+    };
+}
+
+/// Makes a type injectable through a constructor other than `new`, for
+/// types with several constructors where only one should be used by the
+/// container:
+///
+/// ```ignore
+/// impl Database {
+///     pub fn in_memory() -> Self { ... }
+///     pub fn from_config(config: Arc<Config>, logger: Arc<Logger>) -> Self { ... }
+/// }
+///
+/// inject_constructor!(Database => from_config(Arc<Config>, Arc<Logger>));
+/// ```
+///
+/// This is the declarative counterpart of marking the constructor with
+/// `#[inject]` under `#[injectable]`.
+#[macro_export]
+macro_rules! inject_constructor {
+    ($ty:ty => $ctor:ident($(Arc<$dep:ty>),* $(,)?)) => {
+        impl $crate::Injectable for $ty {
+            fn __register<C: $crate::__private::Contract>(container: &C) {
+                container.bind::<$ty, _>(|c| {
+                    ::std::sync::Arc::new(<$ty>::$ctor(
+                        $(<C as $crate::__private::Contract>::resolve::<$dep>(c)),*
+                    ))
+                });
+            }
+        }
+    };
+}
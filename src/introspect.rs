@@ -0,0 +1,125 @@
+use crate::container::Container;
+use crate::lifetime::Lifetime;
+use std::any::TypeId;
+use std::fmt;
+use std::panic::Location;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegisteredBinding {
+    pub type_name: &'static str,
+    pub lifetime: Lifetime,
+    /// Registered on demand by `#[injectable]` rather than bound explicitly.
+    pub implicit: bool,
+    pub sealed: bool,
+    pub resolved: bool,
+    pub location: &'static Location<'static>,
+}
+
+impl Container {
+    /// Whether `T` is bound, or a deferred provider will bind it on first use.
+    pub fn has<T: ?Sized + 'static>(&self) -> bool {
+        let type_id = TypeId::of::<T>();
+        self.factories.lock().unwrap().contains_key(&type_id) || self.is_deferred(type_id)
+    }
+
+    /// Whether `T` has been resolved at least once.
+    pub fn is_resolved<T: ?Sized + 'static>(&self) -> bool {
+        self.resolutions.contains(&TypeId::of::<T>())
+    }
+
+    /// Every registered binding, sorted by type name.
+    pub fn bindings(&self) -> impl Iterator<Item = RegisteredBinding> {
+        let factories = self.factories.lock().unwrap().clone();
+        let resolutions = self.resolutions.snapshot();
+        let sealed = self.sealed.lock().unwrap();
+
+        let mut bindings: Vec<RegisteredBinding> = factories
+            .iter()
+            .map(|(type_id, binding)| RegisteredBinding {
+                type_name: binding.type_name,
+                lifetime: binding.lifetime,
+                implicit: binding.implicit,
+                sealed: sealed.contains(type_id),
+                resolved: resolutions.contains_key(type_id),
+                location: binding.location,
+            })
+            .collect();
+        bindings.sort_by_key(|info| info.type_name);
+        bindings.into_iter()
+    }
+}
+
+impl fmt::Display for Container {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Container #{} ({} bindings, {} instances, {} providers)",
+            self.id,
+            lock(&self.factories).len(),
+            self.instances.len(),
+            lock(&self.providers).len()
+        )
+    }
+}
+
+/// Counts by default; `{:#?}` adds one row per binding.
+impl fmt::Debug for Container {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let factories = lock(&self.factories).clone();
+
+        if f.alternate() {
+            let resolutions = self.resolutions.snapshot();
+            let sealed = lock(&self.sealed).clone();
+            let mut rows: Vec<_> = factories.iter().collect();
+            rows.sort_by_key(|(_, binding)| binding.type_name);
+
+            writeln!(f, "Container #{} {{", self.id)?;
+            for (type_id, binding) in rows {
+                writeln!(
+                    f,
+                    "    {} [{}{}{}{}] resolved {}x, bound at {}",
+                    binding.type_name,
+                    binding.lifetime,
+                    if binding.implicit { ", implicit" } else { "" },
+                    if sealed.contains(type_id) {
+                        ", sealed"
+                    } else {
+                        ""
+                    },
+                    if self.instances.contains(type_id) {
+                        ", cached"
+                    } else {
+                        ""
+                    },
+                    resolutions.get(type_id).copied().unwrap_or(0),
+                    binding.location
+                )?;
+            }
+            return write!(f, "}}");
+        }
+
+        f.debug_struct("Container")
+            .field("id", &self.id)
+            .field("bindings", &factories.len())
+            .field("instances", &self.instances.len())
+            .field("providers", &lock(&self.providers).len())
+            .field("deferred", &lock(&self.deferred).len())
+            .field("named", &lock(&self.named).len())
+            .field("multi", &lock(&self.multi).len())
+            .field(
+                "values",
+                &self
+                    .values
+                    .read()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .len(),
+            )
+            .finish()
+    }
+}
+
+/// Formatting may run inside a panic message, so poisoned locks are read anyway.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
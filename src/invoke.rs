@@ -0,0 +1,107 @@
+use crate::container::Container;
+use crate::trace::ResolvingGuard;
+use luminos_contracts::container::{Contract, Injectable};
+use std::sync::Arc;
+
+/// A handler argument the container can supply on its own.
+pub trait Inject: Sized {
+    fn inject(container: &Container) -> Self;
+}
+
+impl<T> Inject for Arc<T>
+where
+    T: Injectable + Send + Sync + 'static,
+{
+    fn inject(container: &Container) -> Self {
+        container.resolve::<T>()
+    }
+}
+
+/// `Some` only when `T` is bound or already built; unlike `Arc<T>`, the
+/// type's own `Injectable` registration is never triggered.
+impl<T> Inject for Option<Arc<T>>
+where
+    T: Send + Sync + 'static,
+{
+    fn inject(container: &Container) -> Self {
+        container.resolve_optional::<T>()
+    }
+}
+
+/// A non-injectable argument filled from `T::default()`; the runtime half of
+/// `#[default]` constructor parameters.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Defaulted<T>(pub T);
+
+impl<T> Defaulted<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> std::ops::Deref for Defaulted<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Default> Inject for Defaulted<T> {
+    fn inject(_container: &Container) -> Self {
+        Self(T::default())
+    }
+}
+
+/// A method (or closure) taking a receiver followed by injectable arguments.
+pub trait Handler<S: ?Sized, Args, R> {
+    fn call(self, service: &S, container: &Container) -> R;
+}
+
+macro_rules! impl_handler {
+    ($($arg:ident),*) => {
+        impl<S, F, R, $($arg),*> Handler<S, ($($arg,)*), R> for F
+        where
+            S: ?Sized,
+            F: FnOnce(&S, $($arg),*) -> R,
+            $($arg: Inject,)*
+        {
+            #[allow(unused_variables)]
+            fn call(self, service: &S, container: &Container) -> R {
+                self(service, $($arg::inject(container)),*)
+            }
+        }
+    };
+}
+
+impl_handler!();
+impl_handler!(A1);
+impl_handler!(A1, A2);
+impl_handler!(A1, A2, A3);
+impl_handler!(A1, A2, A3, A4);
+impl_handler!(A1, A2, A3, A4, A5);
+impl_handler!(A1, A2, A3, A4, A5, A6);
+impl_handler!(A1, A2, A3, A4, A5, A6, A7);
+impl_handler!(A1, A2, A3, A4, A5, A6, A7, A8);
+
+impl Container {
+    /// Resolves `T` if something provides it (a binding, contextual binding
+    /// or cached instance) and `None` otherwise, for optional dependencies.
+    pub fn resolve_optional<T>(&self) -> Option<Arc<T>>
+    where
+        T: Send + Sync + 'static,
+    {
+        self.contextual::<T>().or_else(|| self.resolve_bound::<T>())
+    }
+
+    /// Calls `handler` on `service`, resolving every other argument from the
+    /// container: `container.invoke(&*service, Service::handle)`.
+    pub fn invoke<S, Args, R, H>(&self, service: &S, handler: H) -> R
+    where
+        S: ?Sized,
+        H: Handler<S, Args, R>,
+    {
+        let _resolving = ResolvingGuard::enter(std::any::type_name::<H>());
+        handler.call(service, self)
+    }
+}
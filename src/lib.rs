@@ -1,6 +1,157 @@
+#[cfg(feature = "actors")]
+mod actors;
+mod args;
+mod async_provider;
+mod audit;
+mod batch;
+mod budget;
+mod builder;
+mod bulkhead;
+mod callbacks;
+mod cancellation;
+mod capabilities;
+mod captive;
+mod channel;
+#[cfg(feature = "chaos")]
+mod chaos;
+mod closure_provider;
 mod container;
+mod contextual;
+mod decorate;
+mod deferred;
+mod dependents;
+#[cfg(feature = "serde")]
+mod deserialize;
+mod diagnostics;
+mod dry_run;
+mod environment;
+mod error;
+mod escape;
+mod ext;
+mod fingerprint;
+mod flags;
+mod flush;
+mod frozen;
+mod injectable_struct;
+mod introspect;
+mod invoke;
+mod lifetime;
+mod local;
+mod manifest;
+mod memo;
+mod meter;
+mod multi;
+mod named;
+mod options;
+mod ordering;
+mod overrides;
+mod persistent;
+mod profile;
+mod promise;
+#[cfg(feature = "config")]
+mod provider_config;
+mod provider_set;
+mod provider_state;
+mod refcount;
+#[cfg(feature = "remote")]
+mod remote;
+mod resolving;
+mod roles;
+mod scope;
+mod secrets;
+mod shadow;
+mod shutdown;
+mod standby;
+mod state_machine;
+mod static_container;
+mod store;
+mod substitute;
+mod systems;
+mod tags;
+mod telemetry;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod trace;
+mod traits;
+mod try_boot;
+mod try_contract;
+mod values;
+mod versioned;
+mod view;
+mod warm_pool;
+mod wrap;
 
+#[cfg(feature = "actors")]
+pub use actors::*;
+pub use args::Args;
+pub use async_provider::{AsyncServiceProvider, BoxFuture};
+pub use audit::*;
+pub use batch::Batch;
+pub use budget::{BudgetReport, ProviderTiming};
+pub use builder::Buildable;
+pub use bulkhead::BulkheadMode;
+pub use callbacks::CallbackRegistry;
+pub use cancellation::*;
+pub use capabilities::Capabilities;
+pub use captive::{CaptiveDependency, VerifyReport};
+pub use channel::{Receiver, Receivers, Sender};
+#[cfg(feature = "chaos")]
+pub use chaos::*;
+pub use closure_provider::ClosureProvider;
 // pub use container::*;
 pub use container::*;
+pub use contextual::{Needs, When};
+pub use deferred::Provides;
+#[cfg(feature = "serde")]
+pub use deserialize::{DeserializeInjected, Injected};
+pub use diagnostics::{BindingInfo, Diagnostics, ProviderInfo};
+pub use dry_run::{DryRunReport, Rebinding};
+pub use environment::Environment;
+pub use error::ContainerError;
+pub use escape::ScopeEscape;
+pub use ext::{ContractExt, InjectedFn};
+pub use flags::{Feature, FeatureFlags, Flag};
+pub use frozen::FrozenContainer;
+pub use introspect::RegisteredBinding;
+pub use invoke::{Defaulted, Handler, Inject};
+pub use lifetime::Lifetime;
 pub use luminos_container_macros::injectable;
 pub use luminos_contracts::container::Injectable;
+pub use manifest::*;
+pub use memo::Memoizer;
+#[cfg(feature = "metrics")]
+pub use meter::MetricsCrateMeter;
+pub use meter::{InMemoryMeter, Meter, Metrics, NoopMeter};
+pub use options::{Options, OptionsError, OptionsErrors};
+pub use ordering::OrderError;
+pub use overrides::OverrideGuard;
+pub use persistent::{FileStateStore, InMemoryStateStore, PersistentState, StateStore};
+pub use profile::*;
+pub use promise::WhenAvailable;
+#[cfg(feature = "config")]
+pub use provider_config::{ConfigError, ConfigSource, ConfigTree, ConfiguredProvider, JsonSource};
+pub use provider_set::{ProviderSet, ProviderSetDiff};
+pub use refcount::RefcountReport;
+#[cfg(feature = "remote")]
+pub use remote::*;
+pub use roles::{Role, RoleRouter, Routed};
+pub use scope::*;
+pub use secrets::*;
+pub use shadow::*;
+pub use shutdown::{ShutdownSignal, Terminate};
+pub use standby::{ContainerHandle, Rebuild, RebuildError};
+pub use state_machine::{StateMachine, Transition, TransitionError};
+pub use static_container::StaticContainer;
+pub use store::*;
+pub use substitute::Substitutions;
+pub use systems::*;
+pub use trace::{current_scope, install_panic_hook, resolution_chain};
+pub use try_boot::{
+    BootPhase, BootReport, FallibleServiceProvider, ProviderError, ProviderFailure,
+};
+pub use try_contract::TryContract;
+pub use view::RestrictedView;
+#[doc(hidden)]
+pub mod __private {
+    pub use luminos_contracts::container::Contract;
+}
@@ -0,0 +1,155 @@
+use crate::container::Container;
+use luminos_contracts::container::Contract;
+use std::any::TypeId;
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Lifetime {
+    /// Built once and cached by the container.
+    #[default]
+    Singleton,
+    /// Built anew on every resolution.
+    Transient,
+    /// Built once per [`Scope`](crate::Scope) and dropped with it.
+    Scoped,
+}
+
+impl fmt::Display for Lifetime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Singleton => "singleton",
+            Self::Transient => "transient",
+            Self::Scoped => "scoped",
+        })
+    }
+}
+
+impl FromStr for Lifetime {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "singleton" => Ok(Self::Singleton),
+            "transient" => Ok(Self::Transient),
+            "scoped" => Ok(Self::Scoped),
+            other => Err(format!("unknown lifetime {other:?}")),
+        }
+    }
+}
+
+/// Whether an override written as `pattern` applies to `type_name`: either
+/// the full path or its trailing segments (`ReportGenerator`, `reports::ReportGenerator`).
+pub(crate) fn matches_type_name(type_name: &str, pattern: &str) -> bool {
+    type_name == pattern
+        || type_name
+            .strip_suffix(pattern)
+            .is_some_and(|prefix| prefix.ends_with("::"))
+}
+
+impl Container {
+    /// Forces every binding whose type name matches `pattern` to `lifetime`,
+    /// both those already registered and those registered later.
+    pub fn override_lifetime(&self, pattern: &str, lifetime: Lifetime) -> &Self {
+        self.lifetime_overrides
+            .lock()
+            .unwrap()
+            .push((pattern.to_string(), lifetime));
+
+        let mut factories = self.factories.lock().unwrap();
+        for binding in Arc::make_mut(&mut factories).values_mut() {
+            if matches_type_name(binding.type_name, pattern) {
+                binding.lifetime = lifetime;
+            }
+        }
+        drop(factories);
+        self.invalidate_warm_pools();
+        self
+    }
+
+    /// Binds `T` so every resolution runs `factory` again instead of
+    /// returning a shared instance.
+    #[track_caller]
+    pub fn bind_transient<T, F>(&self, factory: F) -> &Self
+    where
+        T: Send + Sync + 'static,
+        F: Fn(&Container) -> Arc<T> + Send + Sync + 'static,
+    {
+        self.bind_with_lifetime::<T, F>(Lifetime::Transient, factory)
+    }
+
+    /// Binds `T` with an explicit lifetime. Overrides loaded from
+    /// configuration still take precedence.
+    #[track_caller]
+    pub fn bind_with_lifetime<T, F>(&self, lifetime: Lifetime, factory: F) -> &Self
+    where
+        T: Send + Sync + 'static,
+        F: Fn(&Container) -> Arc<T> + Send + Sync + 'static,
+    {
+        let type_id = TypeId::of::<T>();
+        let type_name = std::any::type_name::<T>();
+        self.bind::<T, F>(factory);
+
+        let lifetime = self.lifetime_override(type_name).unwrap_or(lifetime);
+        if let Some(binding) = Arc::make_mut(&mut self.factories.lock().unwrap()).get_mut(&type_id)
+        {
+            binding.lifetime = lifetime;
+        }
+        if lifetime != Lifetime::Singleton {
+            self.instances.remove(&type_id);
+        }
+        self
+    }
+
+    pub fn lifetime_of<T>(&self) -> Option<Lifetime>
+    where
+        T: ?Sized + 'static,
+    {
+        self.factories
+            .lock()
+            .unwrap()
+            .get(&TypeId::of::<T>())
+            .map(|binding| binding.lifetime)
+    }
+
+    /// Reads overrides from text with one `TypeName = lifetime` per line;
+    /// blank lines and `#` comments are ignored.
+    pub fn load_lifetime_overrides_str(&self, source: &str) -> Result<&Self, String> {
+        for (number, line) in source.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (pattern, lifetime) = line
+                .split_once('=')
+                .ok_or_else(|| format!("line {}: expected `TypeName = lifetime`", number + 1))?;
+            let lifetime = lifetime
+                .parse::<Lifetime>()
+                .map_err(|err| format!("line {}: {err}", number + 1))?;
+
+            self.override_lifetime(pattern.trim().trim_matches('"'), lifetime);
+        }
+
+        Ok(self)
+    }
+
+    pub fn load_lifetime_overrides(&self, path: impl AsRef<Path>) -> io::Result<&Self> {
+        let source = std::fs::read_to_string(path)?;
+        self.load_lifetime_overrides_str(&source)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    pub(crate) fn lifetime_override(&self, type_name: &str) -> Option<Lifetime> {
+        self.lifetime_overrides
+            .lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .find(|(pattern, _)| matches_type_name(type_name, pattern))
+            .map(|(_, lifetime)| *lifetime)
+    }
+}
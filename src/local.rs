@@ -0,0 +1,82 @@
+use crate::container::Container;
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::{Arc, Weak};
+
+pub(crate) type LocalFactory = Arc<dyn Fn(&Container) -> Rc<dyn Any> + Send + Sync>;
+
+/// A thread's instance, with a handle to the owning container's liveness
+/// token so entries of dropped containers can be pruned.
+type LocalEntry = (Weak<()>, Rc<dyn Any>);
+
+thread_local! {
+    static LOCAL_INSTANCES: RefCell<HashMap<(u64, TypeId), LocalEntry>> =
+        RefCell::new(HashMap::new());
+}
+
+impl Container {
+    /// Binds a service that is not `Send`/`Sync`. Each thread gets its own
+    /// instance, handed out as an `Rc<T>` so it cannot leave that thread.
+    #[track_caller]
+    pub fn bind_local<T, F>(&self, factory: F) -> &Self
+    where
+        T: 'static,
+        F: Fn(&Container) -> T + Send + Sync + 'static,
+    {
+        let factory: LocalFactory = Arc::new(move |c| Rc::new(factory(c)) as Rc<dyn Any>);
+        self.local_factories
+            .lock()
+            .unwrap()
+            .insert(TypeId::of::<T>(), factory);
+        self
+    }
+
+    /// This thread's instance of a [`bind_local`](Container::bind_local) service.
+    pub fn resolve_local<T: 'static>(&self) -> Rc<T> {
+        let key = (self.id, TypeId::of::<T>());
+
+        if let Some((_, inst)) = LOCAL_INSTANCES.with(|locals| locals.borrow().get(&key).cloned()) {
+            return inst.downcast::<T>().unwrap();
+        }
+
+        let factory = self
+            .local_factories
+            .lock()
+            .unwrap()
+            .get(&key.1)
+            .cloned()
+            .unwrap_or_else(|| {
+                panic!(
+                    "Failed to resolve local type: {:?}",
+                    std::any::type_name::<T>()
+                )
+            });
+
+        let built = factory(self);
+        LOCAL_INSTANCES.with(|locals| {
+            let mut locals = locals.borrow_mut();
+            // Containers dropped on other threads leave their entries here.
+            locals.retain(|_, (alive, _)| alive.strong_count() > 0);
+            locals.insert(key, (Arc::downgrade(&self.alive), built.clone()));
+        });
+        built.downcast::<T>().unwrap()
+    }
+
+    /// Drops this thread's cached local instances for this container.
+    pub fn forget_locals(&self) {
+        forget_thread_locals(self.id);
+    }
+}
+
+/// Drops the calling thread's local instances of the container `id`.
+/// Other threads prune lazily; `try_with` because the thread-local may
+/// already be gone when a container drops during thread exit.
+pub(crate) fn forget_thread_locals(id: u64) {
+    let _ = LOCAL_INSTANCES.try_with(|locals| {
+        if let Ok(mut locals) = locals.try_borrow_mut() {
+            locals.retain(|(owner, _), _| *owner != id);
+        }
+    });
+}
@@ -0,0 +1,230 @@
+use std::fmt;
+use std::panic::Location;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+impl Version {
+    pub const fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    pub fn parse(input: &str) -> Option<Self> {
+        let mut parts = input.trim().splitn(3, '.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next().map_or(Some(0), |p| p.parse().ok())?;
+        let patch = parts.next().map_or(Some(0), |p| p.parse().ok())?;
+
+        Some(Self::new(major, minor, patch))
+    }
+
+    /// Caret compatibility: `self` satisfies a `^required` requirement.
+    pub fn satisfies(&self, required: &Version) -> bool {
+        if self < required {
+            return false;
+        }
+
+        match (required.major, required.minor) {
+            (0, 0) => self.major == 0 && self.minor == 0 && self.patch == required.patch,
+            (0, minor) => self.major == 0 && self.minor == minor,
+            (major, _) => self.major == major,
+        }
+    }
+
+    /// Whether both versions fall in the same caret range, so either can
+    /// stand in for the other: `1.2` and `1.5` do, `0.1` and `0.2` don't.
+    pub fn is_compatible_with(&self, other: &Version) -> bool {
+        self.satisfies(other) || other.satisfies(self)
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Describes a provider shipped from a separate crate: what it is, which
+/// contracts it implements and which contracts it expects someone else to provide.
+#[derive(Debug, Clone)]
+pub struct ProviderManifest {
+    pub name: &'static str,
+    pub version: Version,
+    pub provides: Vec<(&'static str, Version)>,
+    pub requires: Vec<(&'static str, Version)>,
+}
+
+impl ProviderManifest {
+    pub fn new(name: &'static str, version: Version) -> Self {
+        Self {
+            name,
+            version,
+            provides: Vec::new(),
+            requires: Vec::new(),
+        }
+    }
+
+    pub fn provides(mut self, contract: &'static str, version: Version) -> Self {
+        self.provides.push((contract, version));
+        self
+    }
+
+    pub fn requires(mut self, contract: &'static str, version: Version) -> Self {
+        self.requires.push((contract, version));
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ManifestConflict {
+    DuplicateProvider {
+        name: &'static str,
+        first_added_at: &'static Location<'static>,
+    },
+    IncompatibleContract {
+        contract: &'static str,
+        first: (&'static str, Version, &'static Location<'static>),
+        second: (&'static str, Version, &'static Location<'static>),
+    },
+    MissingContract {
+        provider: &'static str,
+        added_at: &'static Location<'static>,
+        contract: &'static str,
+        required: Version,
+    },
+    UnsatisfiedContract {
+        provider: &'static str,
+        added_at: &'static Location<'static>,
+        contract: &'static str,
+        required: Version,
+        found: (&'static str, Version, &'static Location<'static>),
+    },
+}
+
+impl fmt::Display for ManifestConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateProvider {
+                name,
+                first_added_at,
+            } => write!(
+                f,
+                "provider `{name}` was added more than once (first added at {first_added_at})"
+            ),
+            Self::IncompatibleContract {
+                contract,
+                first,
+                second,
+            } => write!(
+                f,
+                "contract `{contract}` is provided by `{}` ({}, added at {}) and `{}` ({}, added at {})",
+                first.0, first.1, first.2, second.0, second.1, second.2
+            ),
+            Self::MissingContract {
+                provider,
+                added_at,
+                contract,
+                required,
+            } => write!(
+                f,
+                "provider `{provider}` (added at {added_at}) requires contract `{contract}` ^{required}, but no provider supplies it"
+            ),
+            Self::UnsatisfiedContract {
+                provider,
+                added_at,
+                contract,
+                required,
+                found,
+            } => write!(
+                f,
+                "provider `{provider}` (added at {added_at}) requires contract `{contract}` ^{required}, but `{}` (added at {}) provides {}",
+                found.0, found.2, found.1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ManifestConflict {}
+
+/// A registered manifest and where its provider was added.
+#[derive(Debug, Clone)]
+pub(crate) struct AddedManifest {
+    pub(crate) manifest: ProviderManifest,
+    pub(crate) added_at: &'static Location<'static>,
+}
+
+/// Conflicts introduced by adding `added` on top of `existing`.
+pub(crate) fn conflicts_with(
+    existing: &[AddedManifest],
+    added: &AddedManifest,
+) -> Vec<ManifestConflict> {
+    let mut conflicts = Vec::new();
+    let manifest = &added.manifest;
+
+    if let Some(other) = existing.iter().find(|m| m.manifest.name == manifest.name) {
+        conflicts.push(ManifestConflict::DuplicateProvider {
+            name: manifest.name,
+            first_added_at: other.added_at,
+        });
+    }
+
+    for (contract, version) in &manifest.provides {
+        for other in existing {
+            for (other_contract, other_version) in &other.manifest.provides {
+                if other_contract == contract && !other_version.is_compatible_with(version) {
+                    conflicts.push(ManifestConflict::IncompatibleContract {
+                        contract,
+                        first: (other.manifest.name, *other_version, other.added_at),
+                        second: (manifest.name, *version, added.added_at),
+                    });
+                }
+            }
+        }
+    }
+
+    conflicts
+}
+
+/// Requirements that no registered manifest satisfies.
+pub(crate) fn unmet_requirements(manifests: &[AddedManifest]) -> Vec<ManifestConflict> {
+    let mut conflicts = Vec::new();
+
+    for added in manifests {
+        let manifest = &added.manifest;
+        for (contract, required) in &manifest.requires {
+            let candidates: Vec<_> = manifests
+                .iter()
+                .flat_map(|m| m.manifest.provides.iter().map(move |p| (m, p)))
+                .filter(|(_, (c, _))| c == contract)
+                .collect();
+
+            if candidates.is_empty() {
+                conflicts.push(ManifestConflict::MissingContract {
+                    provider: manifest.name,
+                    added_at: added.added_at,
+                    contract,
+                    required: *required,
+                });
+            } else if !candidates.iter().any(|(_, (_, v))| v.satisfies(required)) {
+                let (other, (_, version)) = candidates[0];
+                conflicts.push(ManifestConflict::UnsatisfiedContract {
+                    provider: manifest.name,
+                    added_at: added.added_at,
+                    contract,
+                    required: *required,
+                    found: (other.manifest.name, *version, other.added_at),
+                });
+            }
+        }
+    }
+
+    conflicts
+}
@@ -0,0 +1,80 @@
+use crate::container::Container;
+use luminos_contracts::container::{Contract, Injectable};
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+type Entry = (Instant, Arc<dyn Any + Send + Sync>);
+
+/// Shared "remember this result for a while" helper for injected services,
+/// so each one doesn't grow its own cache map and locking.
+///
+/// Entries live in process memory rather than behind a cache contract: the
+/// contracts crate defines no cache contract to build on yet.
+#[derive(Default)]
+pub struct Memoizer {
+    entries: Mutex<HashMap<(String, TypeId), Entry>>,
+}
+
+impl Memoizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the value stored under `key` if it is younger than `ttl`,
+    /// otherwise computes, stores and returns a fresh one.
+    ///
+    /// The lock is not held while `compute` runs, so concurrent misses for
+    /// the same key may each compute a value; the last one wins.
+    pub fn remember<V, F>(&self, key: &str, ttl: Duration, compute: F) -> V
+    where
+        V: Clone + Send + Sync + 'static,
+        F: FnOnce() -> V,
+    {
+        let slot = (key.to_string(), TypeId::of::<V>());
+
+        if let Some((expires, value)) = self.entries.lock().unwrap().get(&slot)
+            && *expires > Instant::now()
+        {
+            return value.downcast_ref::<V>().unwrap().clone();
+        }
+
+        let value = compute();
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(slot, (Instant::now() + ttl, Arc::new(value.clone())));
+        value
+    }
+
+    pub fn forget(&self, key: &str) {
+        self.entries.lock().unwrap().retain(|(k, _), _| k != key);
+    }
+
+    pub fn flush(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    /// Drops entries whose time-to-live has run out.
+    pub fn prune(&self) {
+        let now = Instant::now();
+        self.entries
+            .lock()
+            .unwrap()
+            .retain(|_, (expires, _)| *expires > now);
+    }
+}
+
+impl Injectable for Memoizer {
+    fn __register<C: Contract>(container: &C) {
+        container.bind::<Memoizer, _>(|_| Arc::new(Memoizer::new()));
+    }
+}
+
+impl Container {
+    /// The container-wide [`Memoizer`], created on first use.
+    pub fn memoizer(&self) -> Arc<Memoizer> {
+        self.resolve::<Memoizer>()
+    }
+}
@@ -0,0 +1,181 @@
+use crate::container::Container;
+use luminos_contracts::container::{Contract, Injectable};
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::ops::Deref;
+use std::sync::{Arc, Mutex};
+
+/// Records application metrics. Services depend on [`Metrics`] rather than
+/// a concrete backend, so tests can swap in an [`InMemoryMeter`].
+pub trait Meter: Send + Sync {
+    fn counter(&self, name: &str, value: u64);
+    fn gauge(&self, name: &str, value: f64);
+    fn histogram(&self, name: &str, value: f64);
+}
+
+/// Discards everything; the default when no meter is configured.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopMeter;
+
+impl Meter for NoopMeter {
+    fn counter(&self, _name: &str, _value: u64) {}
+
+    fn gauge(&self, _name: &str, _value: f64) {}
+
+    fn histogram(&self, _name: &str, _value: f64) {}
+}
+
+#[derive(Default)]
+struct Recorded {
+    counters: HashMap<String, u64>,
+    gauges: HashMap<String, f64>,
+    histograms: HashMap<String, Vec<f64>>,
+}
+
+/// Keeps every recorded value so tests can assert on them. Clones share
+/// the same recordings.
+#[derive(Clone, Default)]
+pub struct InMemoryMeter {
+    recorded: Arc<Mutex<Recorded>>,
+}
+
+impl InMemoryMeter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sum of all increments of `name`.
+    pub fn counter_value(&self, name: &str) -> u64 {
+        self.recorded
+            .lock()
+            .unwrap()
+            .counters
+            .get(name)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// The last value `name` was set to.
+    pub fn gauge_value(&self, name: &str) -> Option<f64> {
+        self.recorded.lock().unwrap().gauges.get(name).copied()
+    }
+
+    pub fn histogram_values(&self, name: &str) -> Vec<f64> {
+        self.recorded
+            .lock()
+            .unwrap()
+            .histograms
+            .get(name)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn clear(&self) {
+        *self.recorded.lock().unwrap() = Recorded::default();
+    }
+}
+
+impl Meter for InMemoryMeter {
+    fn counter(&self, name: &str, value: u64) {
+        *self
+            .recorded
+            .lock()
+            .unwrap()
+            .counters
+            .entry(name.to_string())
+            .or_default() += value;
+    }
+
+    fn gauge(&self, name: &str, value: f64) {
+        self.recorded
+            .lock()
+            .unwrap()
+            .gauges
+            .insert(name.to_string(), value);
+    }
+
+    fn histogram(&self, name: &str, value: f64) {
+        self.recorded
+            .lock()
+            .unwrap()
+            .histograms
+            .entry(name.to_string())
+            .or_default()
+            .push(value);
+    }
+}
+
+/// Forwards to whatever recorder is installed for the `metrics` crate.
+#[cfg(feature = "metrics")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MetricsCrateMeter;
+
+#[cfg(feature = "metrics")]
+impl Meter for MetricsCrateMeter {
+    fn counter(&self, name: &str, value: u64) {
+        metrics::counter!(name.to_string()).increment(value);
+    }
+
+    fn gauge(&self, name: &str, value: f64) {
+        metrics::gauge!(name.to_string()).set(value);
+    }
+
+    fn histogram(&self, name: &str, value: f64) {
+        metrics::histogram!(name.to_string()).record(value);
+    }
+}
+
+/// The injectable handle to the configured [`Meter`].
+#[derive(Clone)]
+pub struct Metrics {
+    meter: Arc<dyn Meter>,
+}
+
+impl Metrics {
+    pub fn new(meter: impl Meter + 'static) -> Self {
+        Self {
+            meter: Arc::new(meter),
+        }
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new(NoopMeter)
+    }
+}
+
+impl Deref for Metrics {
+    type Target = dyn Meter + 'static;
+
+    fn deref(&self) -> &(dyn Meter + 'static) {
+        &*self.meter
+    }
+}
+
+impl Injectable for Metrics {
+    fn __register<C: Contract>(container: &C) {
+        container.bind::<Metrics, _>(|_| Arc::new(Metrics::default()));
+    }
+}
+
+impl Container {
+    /// Replaces the [`Meter`] behind the container's [`Metrics`] binding.
+    /// With the `tracing` or `metrics` feature, sampled resolutions are
+    /// recorded to it as well.
+    pub fn set_meter(&self, meter: impl Meter + 'static) -> &Self {
+        let metrics = Metrics::new(meter);
+        #[cfg(any(feature = "tracing", feature = "metrics"))]
+        {
+            *self.sampling.meter.write().unwrap() = Some(metrics.clone());
+        }
+        // `bind` keeps a cached instance, so drop the one built with the old meter.
+        self.bind::<Metrics, _>(move |_| Arc::new(metrics.clone()));
+        self.instances.remove(&TypeId::of::<Metrics>());
+        self
+    }
+
+    pub fn metrics(&self) -> Arc<Metrics> {
+        self.resolve::<Metrics>()
+    }
+}
@@ -0,0 +1,100 @@
+use crate::container::Container;
+use crate::store::Instance;
+use crate::trace::ResolvingGuard;
+use std::any::TypeId;
+use std::sync::Arc;
+
+type MultiFactory = Arc<dyn Fn(&Container) -> Instance + Send + Sync>;
+
+/// Every implementation appended for one type, each built at most once.
+#[derive(Clone, Default)]
+pub(crate) struct MultiBindings {
+    pub(crate) type_name: &'static str,
+    factories: Vec<MultiFactory>,
+    instances: Vec<Option<Instance>>,
+}
+
+impl MultiBindings {
+    /// The same factories with nothing built yet.
+    pub(crate) fn without_instances(&self) -> Self {
+        Self {
+            type_name: self.type_name,
+            factories: self.factories.clone(),
+            instances: vec![None; self.factories.len()],
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.factories.len()
+    }
+}
+
+impl Container {
+    /// Appends an implementation of `T` (usually a trait object) instead of
+    /// replacing the previous one, for plugin-style handler lists.
+    pub fn bind_many<T, F>(&self, factory: F) -> &Self
+    where
+        T: ?Sized + Send + Sync + 'static,
+        F: Fn(&Container) -> Arc<T> + Send + Sync + 'static,
+    {
+        let factory: MultiFactory = Arc::new(move |c| Arc::new(factory(c)) as Instance);
+        let mut multi = self.multi.lock().unwrap();
+        let entry = multi.entry(TypeId::of::<T>()).or_default();
+
+        entry.type_name = std::any::type_name::<T>();
+        entry.factories.push(factory);
+        entry.instances.push(None);
+        self
+    }
+
+    /// Every implementation bound with `bind_many`, in binding order.
+    pub fn resolve_all<T>(&self) -> Vec<Arc<T>>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        let type_id = TypeId::of::<T>();
+        let pending: Vec<(usize, Option<Instance>, MultiFactory)> =
+            match self.multi.lock().unwrap().get(&type_id) {
+                Some(entry) => entry
+                    .factories
+                    .iter()
+                    .zip(&entry.instances)
+                    .enumerate()
+                    .map(|(index, (factory, inst))| (index, inst.clone(), factory.clone()))
+                    .collect(),
+                None => return Vec::new(),
+            };
+
+        pending
+            .into_iter()
+            .map(|(index, cached, factory)| {
+                let inst = cached.unwrap_or_else(|| {
+                    let built = {
+                        let _resolving = ResolvingGuard::enter(std::any::type_name::<T>());
+                        factory(self)
+                    };
+                    let mut multi = self.multi.lock().unwrap();
+                    match multi
+                        .get_mut(&type_id)
+                        .and_then(|e| e.instances.get_mut(index))
+                    {
+                        Some(slot) => slot.get_or_insert(built).clone(),
+                        None => built,
+                    }
+                });
+                inst.downcast_ref::<Arc<T>>().unwrap().clone()
+            })
+            .collect()
+    }
+
+    pub fn count_all<T>(&self) -> usize
+    where
+        T: ?Sized + 'static,
+    {
+        self.multi
+            .lock()
+            .unwrap()
+            .get(&TypeId::of::<T>())
+            .map_or(0, |entry| entry.factories.len())
+    }
+}
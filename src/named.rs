@@ -0,0 +1,115 @@
+use crate::container::Container;
+use crate::store::Instance;
+use crate::trace::{BuildingGuard, ResolvingGuard};
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+type NamedFactory = Arc<dyn Fn(&Container) -> Instance + Send + Sync>;
+
+#[derive(Clone, Default)]
+pub(crate) struct NamedBindings {
+    pub(crate) type_name: &'static str,
+    factories: HashMap<String, NamedFactory>,
+    instances: HashMap<String, Instance>,
+}
+
+impl NamedBindings {
+    /// The same factories with nothing built yet.
+    pub(crate) fn without_instances(&self) -> Self {
+        Self {
+            type_name: self.type_name,
+            factories: self.factories.clone(),
+            instances: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn names(&self) -> impl Iterator<Item = &str> {
+        self.factories.keys().map(String::as_str)
+    }
+}
+
+impl Container {
+    /// Binds `T` under `name`, next to the unnamed binding and any other
+    /// names, e.g. separate read and write pools of one type.
+    pub fn bind_named<T, F>(&self, name: &str, factory: F) -> &Self
+    where
+        T: Send + Sync + 'static,
+        F: Fn(&Container) -> Arc<T> + Send + Sync + 'static,
+    {
+        let factory: NamedFactory = Arc::new(move |c| factory(c) as Instance);
+        let mut named = self.named.lock().unwrap();
+        let entry = named.entry(TypeId::of::<T>()).or_default();
+
+        entry.type_name = std::any::type_name::<T>();
+        entry.instances.remove(name);
+        entry.factories.insert(name.to_string(), factory);
+        self
+    }
+
+    pub fn names_of<T>(&self) -> Vec<String>
+    where
+        T: 'static,
+    {
+        let mut names: Vec<String> = self
+            .named
+            .lock()
+            .unwrap()
+            .get(&TypeId::of::<T>())
+            .map(|entry| entry.factories.keys().cloned().collect())
+            .unwrap_or_default();
+        names.sort();
+        names
+    }
+
+    pub fn get_named<T>(&self, name: &str) -> Option<Arc<T>>
+    where
+        T: Send + Sync + 'static,
+    {
+        let type_id = TypeId::of::<T>();
+
+        let factory = {
+            let named = self.named.lock().unwrap();
+            let entry = named.get(&type_id)?;
+
+            if let Some(inst) = entry.instances.get(name) {
+                return inst.clone().downcast::<T>().ok();
+            }
+            entry.factories.get(name)?.clone()
+        };
+
+        let built = {
+            let type_name = std::any::type_name::<T>();
+            let _building = BuildingGuard::enter_named(type_id, name, type_name);
+            let _resolving = ResolvingGuard::enter(type_name);
+            factory(self)
+        };
+        let inst = self
+            .named
+            .lock()
+            .unwrap()
+            .get_mut(&type_id)
+            .map(|entry| {
+                entry
+                    .instances
+                    .entry(name.to_string())
+                    .or_insert(built.clone())
+                    .clone()
+            })
+            .unwrap_or(built);
+
+        inst.downcast::<T>().ok()
+    }
+
+    pub fn resolve_named<T>(&self, name: &str) -> Arc<T>
+    where
+        T: Send + Sync + 'static,
+    {
+        self.get_named::<T>(name).unwrap_or_else(|| {
+            panic!(
+                "Failed to resolve type: {:?} (no binding named {name:?})",
+                std::any::type_name::<T>()
+            )
+        })
+    }
+}
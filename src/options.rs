@@ -0,0 +1,178 @@
+use crate::container::Container;
+use luminos_contracts::container::{Contract, Injectable};
+use std::any::{Any, TypeId};
+use std::fmt;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// Settings of type `T` with every configured layer applied, in registration order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options<T>(T);
+
+impl<T> Options<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Options<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+// Only reached for settings nobody configured or validated; those calls
+// bind `Options<T>` themselves.
+impl<T: Default + Send + Sync + 'static> Injectable for Options<T> {
+    fn __register<C: Contract>(container: &C) {
+        container.bind::<Options<T>, _>(|_| Arc::new(Options(T::default())));
+    }
+}
+
+type OptionsLayer<T> = Arc<dyn Fn(&mut T) + Send + Sync>;
+
+pub(crate) type OptionsValidator = Arc<dyn Fn(&Container) -> Option<OptionsError> + Send + Sync>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptionsError {
+    pub options: &'static str,
+    pub message: String,
+}
+
+/// Every options validation failure found in one pass.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptionsErrors(pub Vec<OptionsError>);
+
+impl fmt::Display for OptionsErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} invalid option set(s):", self.0.len())?;
+        for error in &self.0 {
+            writeln!(f, "  {}: {}", error.options, error.message)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for OptionsErrors {}
+
+impl Container {
+    /// Adds a configuration layer for `T`. Any provider may call this; all
+    /// layers are merged on top of `T::default()` when `Options<T>` is resolved.
+    #[track_caller]
+    pub fn configure_options<T, F>(&self, layer: F) -> &Self
+    where
+        T: Default + Send + Sync + 'static,
+        F: Fn(&mut T) + Send + Sync + 'static,
+    {
+        let layer: OptionsLayer<T> = Arc::new(layer);
+        self.configurers
+            .lock()
+            .unwrap()
+            .entry(TypeId::of::<Options<T>>())
+            .or_default()
+            .push(Arc::new(layer));
+
+        self.bind_options::<T>();
+        self
+    }
+
+    /// The merged `Options<T>`, the same as resolving or injecting it.
+    pub fn options<T>(&self) -> Arc<Options<T>>
+    where
+        T: Default + Send + Sync + 'static,
+    {
+        self.resolve::<Options<T>>()
+    }
+
+    /// Binds `Options<T>` to merge its layers and run its validators when
+    /// built. A late layer or validator invalidates options merged earlier.
+    #[track_caller]
+    fn bind_options<T>(&self)
+    where
+        T: Default + Send + Sync + 'static,
+    {
+        self.bind::<Options<T>, _>(|c| Arc::new(c.build_options::<T>()));
+        self.instances.remove(&TypeId::of::<Options<T>>());
+    }
+
+    fn build_options<T>(&self) -> Options<T>
+    where
+        T: Default + Send + Sync + 'static,
+    {
+        let validators: Vec<OptionsValidator> = self
+            .options_validators
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(type_id, _)| *type_id == TypeId::of::<Options<T>>())
+            .map(|(_, validator)| validator.clone())
+            .collect();
+        let errors: Vec<OptionsError> = validators.iter().filter_map(|v| v(self)).collect();
+        if !errors.is_empty() {
+            panic!(
+                "Failed to resolve type: {:?} ({})",
+                std::any::type_name::<Options<T>>(),
+                OptionsErrors(errors).to_string().trim_end()
+            );
+        }
+
+        Options(self.merge_options::<T>())
+    }
+
+    /// Registers a check run against the merged `T` by
+    /// [`Container::validate_options`], which `boot` calls once providers
+    /// have registered. Resolving `Options<T>` runs it as well.
+    #[track_caller]
+    pub fn validate_options_with<T, F>(&self, validator: F) -> &Self
+    where
+        T: Default + Send + Sync + 'static,
+        F: Fn(&T) -> Result<(), String> + Send + Sync + 'static,
+    {
+        let validator: OptionsValidator = Arc::new(move |c| {
+            validator(&c.merge_options::<T>())
+                .err()
+                .map(|message| OptionsError {
+                    options: std::any::type_name::<T>(),
+                    message,
+                })
+        });
+        self.options_validators
+            .lock()
+            .unwrap()
+            .push((TypeId::of::<Options<T>>(), validator));
+        self.bind_options::<T>();
+        self
+    }
+
+    pub fn validate_options(&self) -> Result<(), OptionsErrors> {
+        let validators = self.options_validators.lock().unwrap().clone();
+        let errors: Vec<OptionsError> = validators.iter().filter_map(|(_, v)| v(self)).collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(OptionsErrors(errors))
+        }
+    }
+
+    pub(crate) fn merge_options<T>(&self) -> T
+    where
+        T: Default + Send + Sync + 'static,
+    {
+        let layers: Vec<Arc<dyn Any + Send + Sync>> = self
+            .configurers
+            .lock()
+            .unwrap()
+            .get(&TypeId::of::<Options<T>>())
+            .cloned()
+            .unwrap_or_default();
+
+        let mut value = T::default();
+        for layer in &layers {
+            (layer.downcast_ref::<OptionsLayer<T>>().unwrap())(&mut value);
+        }
+        value
+    }
+}
@@ -0,0 +1,160 @@
+use crate::container::{Container, ProviderEntry};
+use luminos_contracts::support::ServiceProvider;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderError {
+    Cycle(Vec<&'static str>),
+    MissingDependency {
+        node: &'static str,
+        dependency: &'static str,
+    },
+}
+
+impl fmt::Display for OrderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cycle(chain) => write!(f, "dependency cycle: {}", chain.join(" -> ")),
+            Self::MissingDependency { node, dependency } => {
+                write!(
+                    f,
+                    "`{node}` depends on `{dependency}`, which is not registered"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for OrderError {}
+
+/// Orders `nodes` (name, dependencies) so every node comes after what it
+/// depends on, keeping the original order wherever there is no constraint.
+/// Nodes with an empty name are anonymous and cannot be depended on.
+pub(crate) fn topological_order(
+    nodes: &[(&'static str, Vec<&'static str>)],
+) -> Result<Vec<usize>, OrderError> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Mark {
+        Unvisited,
+        Visiting,
+        Done,
+    }
+
+    fn visit(
+        index: usize,
+        nodes: &[(&'static str, Vec<&'static str>)],
+        marks: &mut [Mark],
+        stack: &mut Vec<&'static str>,
+        order: &mut Vec<usize>,
+    ) -> Result<(), OrderError> {
+        match marks[index] {
+            Mark::Done => return Ok(()),
+            Mark::Visiting => {
+                let name = nodes[index].0;
+                let start = stack.iter().position(|n| *n == name).unwrap_or(0);
+                let mut chain = stack[start..].to_vec();
+                chain.push(name);
+                return Err(OrderError::Cycle(chain));
+            }
+            Mark::Unvisited => {}
+        }
+
+        marks[index] = Mark::Visiting;
+        stack.push(nodes[index].0);
+
+        for dependency in &nodes[index].1 {
+            let Some(dep_index) = nodes
+                .iter()
+                .position(|(name, _)| !name.is_empty() && name == dependency)
+            else {
+                return Err(OrderError::MissingDependency {
+                    node: nodes[index].0,
+                    dependency,
+                });
+            };
+            visit(dep_index, nodes, marks, stack, order)?;
+        }
+
+        stack.pop();
+        marks[index] = Mark::Done;
+        order.push(index);
+        Ok(())
+    }
+
+    let mut marks = vec![Mark::Unvisited; nodes.len()];
+    let mut order = Vec::with_capacity(nodes.len());
+
+    for index in 0..nodes.len() {
+        visit(index, nodes, &mut marks, &mut Vec::new(), &mut order)?;
+    }
+
+    Ok(order)
+}
+
+/// Reorders `entries` so each provider registers and boots after the
+/// providers it names in `depends_on`.
+pub(crate) fn order_providers(entries: &mut Vec<ProviderEntry>) -> Result<(), OrderError> {
+    if entries.iter().all(|entry| entry.depends_on.is_empty()) {
+        return Ok(());
+    }
+
+    let nodes: Vec<_> = entries
+        .iter()
+        .map(|entry| (entry.name.unwrap_or_default(), entry.depends_on.clone()))
+        .collect();
+    let order = topological_order(&nodes)?;
+
+    let mut slots: Vec<Option<ProviderEntry>> = entries.drain(..).map(Some).collect();
+    entries.extend(order.into_iter().filter_map(|index| slots[index].take()));
+    Ok(())
+}
+
+impl Container {
+    /// Adds a named provider that boots after every provider in
+    /// `depends_on`, regardless of the order they were added in. Providers
+    /// added without a name cannot appear in `depends_on`.
+    #[track_caller]
+    pub fn add_provider_after(
+        &self,
+        name: &'static str,
+        depends_on: &[&'static str],
+        provider: Box<dyn ServiceProvider<Container>>,
+    ) -> &Self {
+        assert!(
+            !name.is_empty() && !depends_on.contains(&""),
+            "Provider names used for ordering must not be empty"
+        );
+        let mut entry = ProviderEntry::new(Some(name), provider);
+        entry.depends_on = depends_on.to_vec();
+        self.providers.lock().unwrap().push(entry);
+        self
+    }
+
+    /// The order `boot` would run the pending providers in, by name.
+    pub fn provider_order(&self) -> Result<Vec<&'static str>, OrderError> {
+        let providers = self.providers.lock().unwrap();
+        let mut nodes: Vec<_> = providers
+            .iter()
+            .map(|entry| {
+                (
+                    entry.priority,
+                    entry.name.unwrap_or_default(),
+                    entry.depends_on.clone(),
+                )
+            })
+            .collect();
+        nodes.sort_by_key(|(priority, _, _)| std::cmp::Reverse(*priority));
+
+        let nodes: Vec<_> = nodes
+            .into_iter()
+            .map(|(_, name, depends_on)| (name, depends_on))
+            .collect();
+        Ok(topological_order(&nodes)?
+            .into_iter()
+            .map(|index| match nodes[index].0 {
+                "" => "<anonymous>",
+                name => name,
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,91 @@
+use crate::container::{Binding, Container};
+use crate::contextual::ContextualBindings;
+use crate::decorate::Decorators;
+use crate::local::LocalFactory;
+use crate::multi::MultiBindings;
+use crate::named::NamedBindings;
+use crate::store::Instance;
+use crate::tags::TaggedService;
+use crate::values::ValueBindings;
+use crate::versioned::VersionedBindings;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::mem;
+use std::sync::{Arc, Mutex};
+
+/// Restores the bindings and singletons captured by `push_overrides` when
+/// dropped, including during a panic unwind. Guards nest; drop order is LIFO.
+#[must_use = "overrides are rolled back as soon as the guard is dropped"]
+pub struct OverrideGuard<'a> {
+    container: &'a Container,
+    factories: Arc<HashMap<TypeId, Binding>>,
+    instances: Vec<(TypeId, Instance)>,
+    local_factories: HashMap<TypeId, LocalFactory>,
+    versions: HashMap<TypeId, VersionedBindings>,
+    named: HashMap<TypeId, NamedBindings>,
+    contextual: ContextualBindings,
+    tags: HashMap<String, Vec<TaggedService>>,
+    multi: HashMap<TypeId, MultiBindings>,
+    decorators: Decorators,
+    values: ValueBindings,
+}
+
+impl Container {
+    /// Snapshots the current bindings of every kind; anything bound or
+    /// resolved while the returned guard lives is discarded when it drops.
+    pub fn push_overrides(&self) -> OverrideGuard<'_> {
+        OverrideGuard {
+            container: self,
+            factories: self.factories.lock().unwrap().clone(),
+            instances: self.instances.snapshot(),
+            local_factories: self.local_factories.lock().unwrap().clone(),
+            versions: self.versions.lock().unwrap().clone(),
+            named: self.named.lock().unwrap().clone(),
+            contextual: self.contextual.lock().unwrap().clone(),
+            tags: self.tags.lock().unwrap().clone(),
+            multi: self.multi.lock().unwrap().clone(),
+            decorators: self.decorators.lock().unwrap().clone(),
+            values: self.values.read().unwrap().clone(),
+        }
+    }
+
+    /// Explicit counterpart to dropping the guard.
+    pub fn pop_overrides(&self, guard: OverrideGuard<'_>) {
+        assert!(
+            std::ptr::eq(self, guard.container),
+            "Override guard belongs to a different container"
+        );
+        drop(guard);
+    }
+}
+
+/// Recovers from poisoning so a panicking test still gets its bindings back.
+fn restore<T: Default>(table: &Mutex<T>, snapshot: &mut T) {
+    *table
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = mem::take(snapshot);
+}
+
+impl Drop for OverrideGuard<'_> {
+    fn drop(&mut self) {
+        let container = self.container;
+        restore(&container.factories, &mut self.factories);
+        restore(&container.local_factories, &mut self.local_factories);
+        restore(&container.versions, &mut self.versions);
+        restore(&container.named, &mut self.named);
+        restore(&container.contextual, &mut self.contextual);
+        restore(&container.tags, &mut self.tags);
+        restore(&container.multi, &mut self.multi);
+        restore(&container.decorators, &mut self.decorators);
+        *container
+            .values
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = mem::take(&mut self.values);
+
+        container.instances.clear();
+        for (type_id, instance) in self.instances.drain(..) {
+            container.instances.insert(type_id, instance);
+        }
+        container.invalidate_warm_pools();
+    }
+}
@@ -0,0 +1,140 @@
+use crate::container::Container;
+use crate::store::Instance;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::io;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// A singleton whose state outlives the process: saved during
+/// [`Container::shutdown`] and restored when the next process builds it.
+pub trait PersistentState: Send + Sync + 'static {
+    /// Key the state is stored under; must be stable across releases.
+    const KEY: &'static str;
+
+    fn save(&self) -> String;
+
+    fn restore(&self, state: &str);
+}
+
+/// Where persisted singleton state is kept between runs.
+pub trait StateStore: Send + Sync {
+    fn load(&self, key: &str) -> Option<String>;
+    fn store(&self, key: &str, state: &str) -> io::Result<()>;
+}
+
+/// One file per key in a directory.
+pub struct FileStateStore {
+    directory: PathBuf,
+}
+
+impl FileStateStore {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.directory.join(key.replace(['/', '\\', ':'], "_"))
+    }
+}
+
+impl StateStore for FileStateStore {
+    fn load(&self, key: &str) -> Option<String> {
+        std::fs::read_to_string(self.path(key)).ok()
+    }
+
+    fn store(&self, key: &str, state: &str) -> io::Result<()> {
+        std::fs::create_dir_all(&self.directory)?;
+        std::fs::write(self.path(key), state)
+    }
+}
+
+/// Keeps state in memory; clones share it, so tests can hand one to a
+/// second container to simulate a restart.
+#[derive(Clone, Default)]
+pub struct InMemoryStateStore {
+    states: Arc<Mutex<HashMap<String, String>>>,
+}
+
+impl InMemoryStateStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.states.lock().unwrap().get(key).cloned()
+    }
+}
+
+impl StateStore for InMemoryStateStore {
+    fn load(&self, key: &str) -> Option<String> {
+        self.get(key)
+    }
+
+    fn store(&self, key: &str, state: &str) -> io::Result<()> {
+        self.states
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), state.to_string());
+        Ok(())
+    }
+}
+
+type SaveFn = Arc<dyn Fn(&Instance) -> Option<String> + Send + Sync>;
+
+#[derive(Default)]
+pub(crate) struct PersistentStates {
+    store: Option<Arc<dyn StateStore>>,
+    entries: Vec<(TypeId, &'static str, &'static str, SaveFn)>,
+}
+
+impl Container {
+    pub fn set_state_store(&self, store: impl StateStore + 'static) -> &Self {
+        self.persistent.lock().unwrap().store = Some(Arc::new(store));
+        self
+    }
+
+    /// Restores `T` from the state store whenever it is built and saves it
+    /// again on shutdown.
+    pub fn persist<T: PersistentState>(&self) -> &Self {
+        self.persistent.lock().unwrap().entries.push((
+            TypeId::of::<T>(),
+            std::any::type_name::<T>(),
+            T::KEY,
+            Arc::new(|inst| inst.downcast_ref::<T>().map(T::save)),
+        ));
+
+        self.after_resolving::<T, _>(|inst, c| {
+            let store = c.persistent.lock().unwrap().store.clone();
+            if let Some(state) = store.and_then(|store| store.load(T::KEY)) {
+                inst.restore(&state);
+            }
+        })
+    }
+
+    /// Saves every persisted singleton that is currently built. Called by
+    /// `shutdown` before instances are dropped.
+    pub fn save_persistent_state(&self) -> io::Result<()> {
+        self.save_persistent_states().map_err(|(_, err)| err)
+    }
+
+    /// Like `save_persistent_state`, naming the type whose state failed.
+    pub(crate) fn save_persistent_states(&self) -> Result<(), (&'static str, io::Error)> {
+        let (store, entries) = {
+            let persistent = self.persistent.lock().unwrap();
+            match &persistent.store {
+                Some(store) => (store.clone(), persistent.entries.clone()),
+                None => return Ok(()),
+            }
+        };
+
+        for (type_id, type_name, key, save) in entries {
+            if let Some(state) = self.instances.get(&type_id).and_then(|inst| save(&inst)) {
+                store.store(key, &state).map_err(|err| (type_name, err))?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use crate::container::Container;
+use crate::lifetime::Lifetime;
+use luminos_contracts::container::Contract;
+use std::any::TypeId;
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileEntry {
+    pub type_name: String,
+    pub duration: Duration,
+}
+
+/// First-resolution order and construction time of every service built
+/// while profiling, in the order their factories finished.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResolutionProfile {
+    pub entries: Vec<ProfileEntry>,
+}
+
+impl ResolutionProfile {
+    /// Reads the format produced by `Display`: one `micros<TAB>type_name` per line.
+    pub fn parse(input: &str) -> Option<Self> {
+        let entries = input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let (micros, type_name) = line.split_once('\t')?;
+                Some(ProfileEntry {
+                    type_name: type_name.to_string(),
+                    duration: Duration::from_micros(micros.trim().parse().ok()?),
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Self { entries })
+    }
+
+    pub fn total(&self) -> Duration {
+        self.entries.iter().map(|e| e.duration).sum()
+    }
+}
+
+impl fmt::Display for ResolutionProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in &self.entries {
+            writeln!(f, "{}\t{}", entry.duration.as_micros(), entry.type_name)?;
+        }
+        Ok(())
+    }
+}
+
+impl Container {
+    /// Boots the container while recording every service built along the way.
+    pub fn profile_boot(&self) -> ResolutionProfile {
+        *self.profiling.lock().unwrap() = Some(Vec::new());
+        self.boot();
+        let entries = self.profiling.lock().unwrap().take().unwrap_or_default();
+
+        ResolutionProfile { entries }
+    }
+
+    /// Builds the services listed in a previous profile on `threads` worker
+    /// threads, slowest first, so they are cached before traffic arrives.
+    /// Only singletons are built, each once: scoped and transient entries
+    /// have nothing to cache, and entries for types that are no longer bound
+    /// are skipped.
+    pub fn prewarm(&self, profile: &ResolutionProfile, threads: usize) -> &Self {
+        let mut work: Vec<(TypeId, Duration)> = {
+            let factories = self.factories.lock().unwrap();
+            profile
+                .entries
+                .iter()
+                .filter_map(|entry| {
+                    factories
+                        .iter()
+                        .find(|(_, b)| b.type_name == entry.type_name)
+                        .filter(|(_, b)| b.lifetime == Lifetime::Singleton)
+                        .map(|(type_id, _)| (*type_id, entry.duration))
+                })
+                .collect()
+        };
+        work.sort_by_key(|(_, duration)| std::cmp::Reverse(*duration));
+        let mut seen = HashSet::new();
+        work.retain(|(type_id, _)| seen.insert(*type_id));
+
+        let budget = self.start_budget();
+        let next = AtomicUsize::new(0);
+        std::thread::scope(|s| {
+            for _ in 0..threads.max(1) {
+                s.spawn(|| {
+                    while let Some((type_id, _)) = work.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        self.resolve_instance(*type_id);
+                    }
+                });
+            }
+        });
+
+        if let Some(budget) = budget {
+            self.finish_budget(budget, &[]);
+        }
+
+        self
+    }
+}
@@ -0,0 +1,66 @@
+use crate::container::Container;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Waker};
+
+pub(crate) type BindingWaiters = HashMap<TypeId, Vec<Waker>>;
+
+/// Completes with `T` once it is bound; see [`Container::resolve_when_available`].
+pub struct WhenAvailable<'c, T> {
+    container: &'c Container,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Send + Sync + 'static> Future for WhenAvailable<'_, T> {
+    type Output = Arc<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Arc<T>> {
+        let container = self.container;
+        if let Some(inst) = container.resolve_bound::<T>() {
+            return Poll::Ready(inst);
+        }
+
+        {
+            let mut waiters = container.binding_waiters.lock().unwrap();
+            let wakers = waiters.entry(TypeId::of::<T>()).or_default();
+            // Repeated polls from the same task replace its waker instead of
+            // piling up copies.
+            match wakers.iter_mut().find(|w| w.will_wake(cx.waker())) {
+                Some(waker) => waker.clone_from(cx.waker()),
+                None => wakers.push(cx.waker().clone()),
+            }
+        }
+
+        // A bind may have landed between the check and registering the waker.
+        match container.resolve_bound::<T>() {
+            Some(inst) => Poll::Ready(inst),
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl Container {
+    /// Resolves `T` as soon as something binds it, e.g. a plugin loaded
+    /// later or another subsystem's provider, so optional subsystems can
+    /// start in any order.
+    pub fn resolve_when_available<T>(&self) -> WhenAvailable<'_, T>
+    where
+        T: Send + Sync + 'static,
+    {
+        WhenAvailable {
+            container: self,
+            marker: PhantomData,
+        }
+    }
+
+    pub(crate) fn wake_binding_waiters(&self, type_id: TypeId) {
+        let wakers = self.binding_waiters.lock().unwrap().remove(&type_id);
+        for waker in wakers.into_iter().flatten() {
+            waker.wake();
+        }
+    }
+}
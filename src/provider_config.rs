@@ -0,0 +1,165 @@
+use crate::container::Container;
+use luminos_contracts::container::Contract;
+use luminos_contracts::support::ServiceProvider;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Application configuration that providers read their sections from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigTree(pub Value);
+
+impl ConfigTree {
+    /// The value at a dot-separated `namespace`, e.g. `database.pool`.
+    pub fn section(&self, namespace: &str) -> Option<&Value> {
+        namespace
+            .split('.')
+            .filter(|segment| !segment.is_empty())
+            .try_fold(&self.0, |value, segment| value.get(segment))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigError {
+    pub namespace: String,
+    pub message: String,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid config section {:?}: {}",
+            self.namespace, self.message
+        )
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// A provider whose settings are deserialized from its own config section
+/// and handed to `register` and `boot`, rather than read ad hoc.
+pub trait ConfiguredProvider: Send + Sync + 'static {
+    type Config: DeserializeOwned + Send + Sync + 'static;
+
+    /// Dot-separated path of the section, e.g. `mail.smtp`. A missing
+    /// section deserializes from an empty object.
+    const NAMESPACE: &'static str;
+
+    fn register(&self, config: &Self::Config, container: &Container);
+
+    fn boot(&self, config: &Self::Config, container: &Container) {
+        let _ = (config, container);
+    }
+}
+
+struct ConfiguredAdapter<P>(P);
+
+impl<P: ConfiguredProvider> ServiceProvider<Container> for ConfiguredAdapter<P> {
+    fn register(&self, container: &Container) {
+        let config = container
+            .provider_config::<P::Config>(P::NAMESPACE)
+            .unwrap_or_else(|err| panic!("{err}"));
+        let config = Arc::new(config);
+
+        *container
+            .provider_state::<Option<Arc<P::Config>>>()
+            .lock()
+            .unwrap() = Some(config.clone());
+        self.0.register(&config, container);
+    }
+
+    fn boot(&self, container: &Container) {
+        let config = container
+            .provider_state::<Option<Arc<P::Config>>>()
+            .lock()
+            .unwrap()
+            .clone()
+            .expect("provider booted before it registered");
+        self.0.boot(&config, container);
+    }
+}
+
+/// Where the configuration tree comes from. Layering and merging stay with
+/// the backend; the container only needs the final tree.
+pub trait ConfigSource {
+    fn load(&self) -> Result<Value, String>;
+}
+
+/// A JSON document already in memory.
+pub struct JsonSource(pub String);
+
+impl ConfigSource for JsonSource {
+    fn load(&self) -> Result<Value, String> {
+        serde_json::from_str(&self.0).map_err(|err| err.to_string())
+    }
+}
+
+#[cfg(feature = "figment")]
+impl ConfigSource for figment::Figment {
+    fn load(&self) -> Result<Value, String> {
+        self.extract().map_err(|err| err.to_string())
+    }
+}
+
+#[cfg(feature = "config-rs")]
+impl ConfigSource for config_rs::Config {
+    fn load(&self) -> Result<Value, String> {
+        self.clone()
+            .try_deserialize()
+            .map_err(|err| err.to_string())
+    }
+}
+
+impl Container {
+    /// Replaces the config tree with whatever `source` produces, e.g. a
+    /// `figment::Figment` or `config::Config` with the matching feature.
+    pub fn load_config_from(&self, source: &impl ConfigSource) -> io::Result<&Self> {
+        let config = source
+            .load()
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        Ok(self.set_config(config))
+    }
+
+    pub fn set_config(&self, config: Value) -> &Self {
+        self.set_internal(Arc::new(ConfigTree(config)));
+        self
+    }
+
+    pub fn load_config_str(&self, json: &str) -> Result<&Self, serde_json::Error> {
+        Ok(self.set_config(serde_json::from_str(json)?))
+    }
+
+    pub fn load_config(&self, path: impl AsRef<Path>) -> io::Result<&Self> {
+        let source = std::fs::read_to_string(path)?;
+        self.load_config_str(&source)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    /// The tree set with `set_config`, or an empty one.
+    pub fn config_tree(&self) -> Arc<ConfigTree> {
+        self.internal::<ConfigTree>()
+            .unwrap_or_else(|| Arc::new(ConfigTree(Value::Object(Default::default()))))
+    }
+
+    pub fn provider_config<T: DeserializeOwned>(&self, namespace: &str) -> Result<T, ConfigError> {
+        let tree = self.config_tree();
+        let section = tree
+            .section(namespace)
+            .cloned()
+            .unwrap_or_else(|| Value::Object(Default::default()));
+
+        serde_json::from_value(section).map_err(|err| ConfigError {
+            namespace: namespace.to_string(),
+            message: err.to_string(),
+        })
+    }
+
+    #[track_caller]
+    pub fn add_configured_provider(&self, provider: impl ConfiguredProvider) -> &Self {
+        self.add_provider(Box::new(ConfiguredAdapter(provider)))
+    }
+}
@@ -0,0 +1,171 @@
+use crate::container::Container;
+use luminos_contracts::support::ServiceProvider;
+use std::fmt;
+use std::sync::Arc;
+
+type ProviderFactory = Arc<dyn Fn() -> Box<dyn ServiceProvider<Container>> + Send + Sync>;
+
+/// The providers one environment wires up, kept as a value so two
+/// environments can be compared before anything is registered.
+#[derive(Clone)]
+pub struct ProviderSet {
+    name: &'static str,
+    entries: Vec<(&'static str, ProviderFactory)>,
+}
+
+impl ProviderSet {
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn production() -> Self {
+        Self::new("production")
+    }
+
+    pub fn staging() -> Self {
+        Self::new("staging")
+    }
+
+    pub fn testing() -> Self {
+        Self::new("testing")
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Adds `P`, named after its type.
+    pub fn with<P>(self) -> Self
+    where
+        P: ServiceProvider<Container> + Default + 'static,
+    {
+        self.with_named(std::any::type_name::<P>(), || P::default())
+    }
+
+    /// Adds a provider built by `factory`; a provider already in the set
+    /// under the same name is replaced.
+    pub fn with_named<P, F>(mut self, name: &'static str, factory: F) -> Self
+    where
+        P: ServiceProvider<Container> + 'static,
+        F: Fn() -> P + Send + Sync + 'static,
+    {
+        let factory: ProviderFactory = Arc::new(move || Box::new(factory()));
+        match self.entries.iter_mut().find(|(n, _)| *n == name) {
+            Some(entry) => entry.1 = factory,
+            None => self.entries.push((name, factory)),
+        }
+        self
+    }
+
+    pub fn without(mut self, name: &str) -> Self {
+        self.entries
+            .retain(|(n, _)| *n != name && !crate::lifetime::matches_type_name(n, name));
+        self
+    }
+
+    pub fn names(&self) -> Vec<&'static str> {
+        self.entries.iter().map(|(name, _)| *name).collect()
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.entries
+            .iter()
+            .any(|(n, _)| *n == name || crate::lifetime::matches_type_name(n, name))
+    }
+
+    pub fn diff(&self, other: &ProviderSet) -> ProviderSetDiff {
+        ProviderSetDiff {
+            left: self.name,
+            right: other.name,
+            removed: self
+                .names()
+                .into_iter()
+                .filter(|name| !other.names().contains(name))
+                .collect(),
+            added: other
+                .names()
+                .into_iter()
+                .filter(|name| !self.names().contains(name))
+                .collect(),
+        }
+    }
+
+    /// Panics unless `other` differs from this set only in the providers
+    /// named by `allowed`.
+    #[track_caller]
+    pub fn assert_differs_only_by(&self, other: &ProviderSet, allowed: &[&str]) {
+        let diff = self.diff(other);
+        let unexpected: Vec<&str> = diff
+            .removed
+            .iter()
+            .chain(&diff.added)
+            .copied()
+            .filter(|name| {
+                !allowed
+                    .iter()
+                    .any(|a| name == a || crate::lifetime::matches_type_name(name, a))
+            })
+            .collect();
+
+        if !unexpected.is_empty() {
+            panic!(
+                "Provider sets differ in unexpected providers: {}\n{diff}",
+                unexpected.join(", ")
+            );
+        }
+    }
+
+    pub fn install(&self, container: &Container) {
+        for (name, factory) in &self.entries {
+            container.add_named_provider(name, factory());
+        }
+    }
+}
+
+impl fmt::Debug for ProviderSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProviderSet")
+            .field("name", &self.name)
+            .field("providers", &self.names())
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProviderSetDiff {
+    pub left: &'static str,
+    pub right: &'static str,
+    /// In `left` but not `right`.
+    pub removed: Vec<&'static str>,
+    /// In `right` but not `left`.
+    pub added: Vec<&'static str>,
+}
+
+impl ProviderSetDiff {
+    pub fn is_empty(&self) -> bool {
+        self.removed.is_empty() && self.added.is_empty()
+    }
+}
+
+impl fmt::Display for ProviderSetDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "--- {}\n+++ {}", self.left, self.right)?;
+        for name in &self.removed {
+            writeln!(f, "- {name}")?;
+        }
+        for name in &self.added {
+            writeln!(f, "+ {name}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Container {
+    pub fn add_provider_set(&self, set: &ProviderSet) -> &Self {
+        set.install(self);
+        self
+    }
+}
@@ -0,0 +1,62 @@
+use crate::container::Container;
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Typed values one provider keeps between `register` and `boot`.
+pub(crate) type StateSlots = Arc<Mutex<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>>;
+
+thread_local! {
+    /// Slots of the provider whose lifecycle method is running on this thread.
+    static CURRENT: RefCell<Vec<StateSlots>> = const { RefCell::new(Vec::new()) };
+}
+
+pub(crate) struct ProviderStateGuard;
+
+impl ProviderStateGuard {
+    pub(crate) fn enter(slots: StateSlots) -> Self {
+        CURRENT.with(|current| current.borrow_mut().push(slots));
+        Self
+    }
+}
+
+impl Drop for ProviderStateGuard {
+    fn drop(&mut self) {
+        CURRENT.with(|current| current.borrow_mut().pop());
+    }
+}
+
+impl Container {
+    /// State private to the provider currently registering or booting, so a
+    /// handle created in `register` can be picked up again in `boot`:
+    ///
+    /// ```ignore
+    /// fn register(&self, container: &Container) {
+    ///     *container.provider_state::<Option<Handle>>().lock().unwrap() = Some(open());
+    /// }
+    /// ```
+    ///
+    /// Each provider gets its own `S`, created with `Default` on first use.
+    pub fn provider_state<S>(&self) -> Arc<Mutex<S>>
+    where
+        S: Default + Send + 'static,
+    {
+        let slots = CURRENT
+            .with(|current| current.borrow().last().cloned())
+            .unwrap_or_else(|| {
+                panic!(
+                    "provider_state::<{}>() called outside a provider's register or boot",
+                    std::any::type_name::<S>()
+                )
+            });
+
+        let mut slots = slots.lock().unwrap();
+        slots
+            .entry(TypeId::of::<S>())
+            .or_insert_with(|| Arc::new(Mutex::new(S::default())))
+            .clone()
+            .downcast::<Mutex<S>>()
+            .unwrap()
+    }
+}
@@ -0,0 +1,90 @@
+use crate::container::Container;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub(crate) type RefcountSamples = HashMap<TypeId, Vec<(usize, usize)>>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefcountReport {
+    pub type_name: String,
+    pub strong: usize,
+    pub weak: usize,
+    /// Strong counts from every sample, oldest first.
+    pub history: Vec<usize>,
+    /// Set when the strong count never dropped and ended above where it started.
+    pub growing: bool,
+}
+
+impl Container {
+    /// Records the current strong/weak counts of every cached instance.
+    /// Call periodically (e.g. after each request) to build a history.
+    pub fn sample_refcounts(&self) -> &Self {
+        let snapshot = self.instances.snapshot();
+        let mut samples = self.refcounts.lock().unwrap();
+
+        for (type_id, inst) in snapshot {
+            // Ignore the reference held by the snapshot itself.
+            let strong = Arc::strong_count(&inst) - 1;
+            let weak = Arc::weak_count(&inst);
+            samples.entry(type_id).or_default().push((strong, weak));
+        }
+
+        self
+    }
+
+    pub fn refcount_report(&self) -> Vec<RefcountReport> {
+        let names = self.factories.lock().unwrap().clone();
+        let samples = self.refcounts.lock().unwrap();
+
+        let mut report: Vec<RefcountReport> = samples
+            .iter()
+            .filter_map(|(type_id, history)| {
+                let &(strong, weak) = history.last()?;
+                let strongs: Vec<usize> = history.iter().map(|(s, _)| *s).collect();
+                let growing = strongs.len() > 2
+                    && strongs.windows(2).all(|w| w[1] >= w[0])
+                    && strongs.last() > strongs.first();
+
+                Some(RefcountReport {
+                    type_name: names
+                        .get(type_id)
+                        .map_or_else(|| format!("{type_id:?}"), |b| b.type_name.to_string()),
+                    strong,
+                    weak,
+                    history: strongs,
+                    growing,
+                })
+            })
+            .collect();
+
+        report.sort_by(|a, b| a.type_name.cmp(&b.type_name));
+        report
+    }
+
+    /// Only the entries whose counts look like a leak, each also emitted as a
+    /// `tracing` warning when that feature is enabled.
+    pub fn warn_refcount_leaks(&self) -> Vec<RefcountReport> {
+        let leaks: Vec<_> = self
+            .refcount_report()
+            .into_iter()
+            .filter(|r| r.growing)
+            .collect();
+
+        #[cfg(feature = "tracing")]
+        for leak in &leaks {
+            tracing::warn!(
+                target: "luminos_container",
+                service = leak.type_name.as_str(),
+                history = ?leak.history,
+                "strong count only grows"
+            );
+        }
+
+        leaks
+    }
+
+    pub fn reset_refcount_samples(&self) {
+        self.refcounts.lock().unwrap().clear();
+    }
+}
@@ -0,0 +1,149 @@
+use crate::container::Container;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::ops::Deref;
+use std::sync::{Arc, RwLock};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Endpoint {
+    pub address: String,
+    pub metadata: HashMap<String, String>,
+}
+
+impl Endpoint {
+    pub fn new(address: impl Into<String>) -> Self {
+        Self {
+            address: address.into(),
+            metadata: HashMap::new(),
+        }
+    }
+
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+}
+
+/// Locates out-of-process services by name.
+pub trait Discovery: Send + Sync {
+    fn locate(&self, service: &str) -> Option<Endpoint>;
+}
+
+/// Fixed name-to-endpoint table, typically loaded from configuration.
+#[derive(Default)]
+pub struct StaticDiscovery {
+    endpoints: RwLock<HashMap<String, Endpoint>>,
+}
+
+impl StaticDiscovery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(self, service: impl Into<String>, endpoint: Endpoint) -> Self {
+        self.insert(service, endpoint);
+        self
+    }
+
+    pub fn insert(&self, service: impl Into<String>, endpoint: Endpoint) {
+        self.endpoints
+            .write()
+            .unwrap()
+            .insert(service.into(), endpoint);
+    }
+}
+
+impl Discovery for StaticDiscovery {
+    fn locate(&self, service: &str) -> Option<Endpoint> {
+        self.endpoints.read().unwrap().get(service).cloned()
+    }
+}
+
+/// Resolves `<service><suffix>:<port>` through the system resolver.
+pub struct DnsDiscovery {
+    suffix: String,
+    port: u16,
+}
+
+impl DnsDiscovery {
+    pub fn new(suffix: impl Into<String>, port: u16) -> Self {
+        Self {
+            suffix: suffix.into(),
+            port,
+        }
+    }
+}
+
+impl Discovery for DnsDiscovery {
+    fn locate(&self, service: &str) -> Option<Endpoint> {
+        let host = format!("{service}{}", self.suffix);
+        let addr: SocketAddr = (host.as_str(), self.port).to_socket_addrs().ok()?.next()?;
+
+        Some(Endpoint::new(addr.to_string()).with_metadata("host", host))
+    }
+}
+
+/// A client stub for a service living in another process.
+pub trait RemoteService: Send + Sync + Sized + 'static {
+    const SERVICE: &'static str;
+
+    fn connect(endpoint: &Endpoint) -> Self;
+}
+
+pub struct Remote<T: RemoteService> {
+    endpoint: Endpoint,
+    client: T,
+}
+
+impl<T: RemoteService> Remote<T> {
+    pub fn endpoint(&self) -> &Endpoint {
+        &self.endpoint
+    }
+}
+
+impl<T: RemoteService> Deref for Remote<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.client
+    }
+}
+
+struct DiscoveryHandle(Arc<dyn Discovery>);
+
+impl Container {
+    pub fn set_discovery(&self, discovery: impl Discovery + 'static) -> &Self {
+        self.set_internal(Arc::new(DiscoveryHandle(Arc::new(discovery))));
+        self
+    }
+
+    pub fn resolve_remote<T: RemoteService>(&self) -> Arc<Remote<T>> {
+        let type_id = TypeId::of::<Remote<T>>();
+
+        if let Some(inst) = self.instances.get(&type_id) {
+            return inst.downcast::<Remote<T>>().unwrap();
+        }
+
+        let discovery = self.internal::<DiscoveryHandle>().unwrap_or_else(|| {
+            panic!(
+                "Failed to resolve remote {:?}: no discovery backend configured",
+                T::SERVICE
+            )
+        });
+
+        let endpoint = discovery.0.locate(T::SERVICE).unwrap_or_else(|| {
+            panic!(
+                "Failed to resolve remote {:?}: service not found",
+                T::SERVICE
+            )
+        });
+
+        let remote = Arc::new(Remote {
+            client: T::connect(&endpoint),
+            endpoint,
+        });
+        self.instances.insert(type_id, remote.clone());
+        remote
+    }
+}
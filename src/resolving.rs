@@ -0,0 +1,109 @@
+use crate::container::Container;
+use crate::store::Instance;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+type Callback = Arc<dyn Fn(&Instance, &Container) + Send + Sync>;
+type GlobalCallback = Arc<dyn Fn(&'static str, &(dyn Any + Send + Sync), &Container) + Send + Sync>;
+
+#[derive(Default, Clone)]
+pub(crate) struct ResolvingCallbacks {
+    resolving: HashMap<TypeId, Vec<Callback>>,
+    after: HashMap<TypeId, Vec<Callback>>,
+    pub(crate) global: Vec<GlobalCallback>,
+}
+
+fn typed<T, F>(callback: F) -> Callback
+where
+    T: Send + Sync + 'static,
+    F: Fn(&Arc<T>, &Container) + Send + Sync + 'static,
+{
+    Arc::new(move |inst, c| {
+        if let Ok(inst) = inst.clone().downcast::<T>() {
+            callback(&inst, c);
+        }
+    })
+}
+
+impl Container {
+    /// Runs `callback` on every newly built `T`, before decorators apply.
+    /// Cached singletons do not trigger it again.
+    pub fn resolving<T, F>(&self, callback: F) -> &Self
+    where
+        T: Send + Sync + 'static,
+        F: Fn(&Arc<T>, &Container) + Send + Sync + 'static,
+    {
+        self.resolving_callbacks
+            .write()
+            .unwrap()
+            .resolving
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .push(typed(callback));
+        self
+    }
+
+    /// Like [`Container::resolving`], but sees the fully decorated instance.
+    pub fn after_resolving<T, F>(&self, callback: F) -> &Self
+    where
+        T: Send + Sync + 'static,
+        F: Fn(&Arc<T>, &Container) + Send + Sync + 'static,
+    {
+        self.resolving_callbacks
+            .write()
+            .unwrap()
+            .after
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .push(typed(callback));
+        self
+    }
+
+    /// Runs `callback` with the type name and instance on every successful
+    /// `resolve`, cached or not.
+    pub fn on_resolved<F>(&self, callback: F) -> &Self
+    where
+        F: Fn(&'static str, &(dyn Any + Send + Sync), &Container) + Send + Sync + 'static,
+    {
+        self.resolving_callbacks
+            .write()
+            .unwrap()
+            .global
+            .push(Arc::new(callback));
+        self
+    }
+
+    pub(crate) fn fire_resolving(&self, type_id: TypeId, built: &Instance, after: bool) {
+        let callbacks = {
+            let registered = self.resolving_callbacks.read().unwrap();
+            let map = if after {
+                &registered.after
+            } else {
+                &registered.resolving
+            };
+            match map.get(&type_id) {
+                Some(callbacks) => callbacks.clone(),
+                None => return,
+            }
+        };
+
+        for callback in callbacks {
+            callback(built, self);
+        }
+    }
+
+    pub(crate) fn fire_resolved(&self, type_name: &'static str, inst: &(dyn Any + Send + Sync)) {
+        let callbacks = {
+            let registered = self.resolving_callbacks.read().unwrap();
+            if registered.global.is_empty() {
+                return;
+            }
+            registered.global.clone()
+        };
+
+        for callback in callbacks {
+            callback(type_name, inst, self);
+        }
+    }
+}
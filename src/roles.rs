@@ -0,0 +1,91 @@
+use crate::container::Container;
+use luminos_contracts::container::{Contract, Injectable};
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// Which service graph a resolution is routed to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    Read,
+    Write,
+}
+
+/// Marker for services that always live in one role's graph.
+pub trait Routed {
+    const ROLE: Role;
+}
+
+/// One facade over several containers sharing the same contracts, e.g. a
+/// read-optimized and a write-optimized graph in a CQRS setup.
+pub struct RoleRouter {
+    containers: HashMap<Role, Arc<Container>>,
+    routes: RwLock<HashMap<TypeId, Role>>,
+    default_role: Role,
+}
+
+impl RoleRouter {
+    pub fn new(default_role: Role) -> Self {
+        Self {
+            containers: HashMap::new(),
+            routes: RwLock::new(HashMap::new()),
+            default_role,
+        }
+    }
+
+    pub fn with_role(mut self, role: Role, container: impl Into<Arc<Container>>) -> Self {
+        self.containers.insert(role, container.into());
+        self
+    }
+
+    /// Sends every later `resolve::<T>()` to `role` instead of the default.
+    pub fn route<T: ?Sized + 'static>(&self, role: Role) -> &Self {
+        self.routes.write().unwrap().insert(TypeId::of::<T>(), role);
+        self
+    }
+
+    pub fn role_of<T: ?Sized + 'static>(&self) -> Role {
+        self.routes
+            .read()
+            .unwrap()
+            .get(&TypeId::of::<T>())
+            .copied()
+            .unwrap_or(self.default_role)
+    }
+
+    pub fn container(&self, role: Role) -> &Container {
+        self.containers
+            .get(&role)
+            .unwrap_or_else(|| panic!("No container registered for role {role:?}"))
+    }
+
+    pub fn resolve_in_role<T>(&self, role: Role) -> Arc<T>
+    where
+        T: Injectable + Send + Sync + 'static,
+    {
+        self.container(role).resolve::<T>()
+    }
+
+    /// Resolves `T` from the role given by `route`, or the default role.
+    pub fn resolve<T>(&self) -> Arc<T>
+    where
+        T: Injectable + Send + Sync + 'static,
+    {
+        self.resolve_in_role::<T>(self.role_of::<T>())
+    }
+
+    pub fn resolve_routed<T>(&self) -> Arc<T>
+    where
+        T: Routed + Injectable + Send + Sync + 'static,
+    {
+        self.resolve_in_role::<T>(T::ROLE)
+    }
+
+    /// Boots every role's container.
+    pub fn boot(&self) -> &Self {
+        for container in self.containers.values() {
+            container.boot();
+        }
+        self
+    }
+}
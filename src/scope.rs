@@ -0,0 +1,178 @@
+use crate::cancellation::{CancellationToken, Deadline};
+use crate::container::Container;
+use crate::lifetime::Lifetime;
+use crate::store::{InstanceStore, MemoryStore};
+use crate::trace::ScopeGuard;
+use luminos_contracts::container::{Contract, Injectable};
+use std::any::TypeId;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static NEXT_SCOPE_ID: AtomicU64 = AtomicU64::new(0);
+
+pub(crate) type ScopeHook = Arc<dyn Fn(&Scope<'_>) + Send + Sync>;
+
+#[derive(Default, Clone)]
+pub(crate) struct ScopeHooks {
+    created: Vec<ScopeHook>,
+    disposed: Vec<ScopeHook>,
+}
+
+/// A child resolution context borrowing its parent container.
+///
+/// Values inserted with [`Scope::insert_local`] are only visible through this
+/// scope and shadow any parent binding of the same type, including where a
+/// factory resolves that type as a dependency. Singletons are built with the
+/// scope hidden, so they never capture its locals. Services bound with
+/// [`Lifetime::Scoped`] are built once per scope and cached here as well. Every scope carries
+/// a [`CancellationToken`] that is cancelled when the scope is dropped; factories
+/// running in the scope resolve it, and any [`Deadline`], like other locals.
+pub struct Scope<'a> {
+    id: u64,
+    parent: &'a Container,
+    locals: Arc<MemoryStore>,
+}
+
+impl<'a> Scope<'a> {
+    /// Installs the scope's token, and `deadline` if any, before the
+    /// creation hooks run, so hooks see the values the scope keeps.
+    pub(crate) fn new(parent: &'a Container, deadline: Option<Deadline>) -> Self {
+        let scope = Self {
+            id: NEXT_SCOPE_ID.fetch_add(1, Ordering::Relaxed),
+            parent,
+            locals: Arc::new(MemoryStore::new()),
+        };
+        match deadline {
+            Some(deadline) => {
+                scope.insert_local(deadline);
+                scope.insert_local(CancellationToken::with_deadline(deadline));
+            }
+            None => {
+                scope.insert_local(CancellationToken::new());
+            }
+        }
+
+        let hooks = parent.scope_hooks.lock().unwrap().created.clone();
+        for hook in hooks {
+            hook(&scope);
+        }
+
+        scope
+    }
+
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.local::<CancellationToken>()
+            .map(|token| (*token).clone())
+            .unwrap_or_default()
+    }
+
+    pub fn deadline(&self) -> Option<Deadline> {
+        self.local::<Deadline>().map(|deadline| *deadline)
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn container(&self) -> &'a Container {
+        self.parent
+    }
+
+    /// Runs `f` with this scope marked as active on the current thread.
+    pub fn enter<R>(&self, f: impl FnOnce(&Self) -> R) -> R {
+        let _scope = ScopeGuard::enter(self.id, self.locals.clone());
+        f(self)
+    }
+
+    pub fn insert_local<T>(&self, value: T) -> &Self
+    where
+        T: Send + Sync + 'static,
+    {
+        self.locals.insert(TypeId::of::<T>(), Arc::new(value));
+        self
+    }
+
+    pub fn local<T>(&self) -> Option<Arc<T>>
+    where
+        T: Send + Sync + 'static,
+    {
+        self.locals
+            .get(&TypeId::of::<T>())
+            .map(|inst| inst.downcast::<T>().unwrap())
+    }
+
+    pub fn resolve<T>(&self) -> Arc<T>
+    where
+        T: Injectable + Send + Sync + 'static,
+    {
+        let _scope = ScopeGuard::enter(self.id, self.locals.clone());
+        self.parent.resolve::<T>()
+    }
+}
+
+impl Drop for Scope<'_> {
+    fn drop(&mut self) {
+        let hooks = self.parent.scope_hooks.lock().unwrap().disposed.clone();
+        for hook in hooks {
+            hook(self);
+        }
+
+        if let Some(token) = self.local::<CancellationToken>() {
+            token.cancel();
+        }
+
+        self.parent.check_scope_escapes(self.id, &self.locals);
+    }
+}
+
+impl Container {
+    pub fn scope(&self) -> Scope<'_> {
+        Scope::new(self, None)
+    }
+
+    /// A scope whose token expires at `deadline`.
+    pub fn scope_with_deadline(&self, deadline: Deadline) -> Scope<'_> {
+        Scope::new(self, Some(deadline))
+    }
+
+    pub fn scope_with_timeout(&self, timeout: std::time::Duration) -> Scope<'_> {
+        self.scope_with_deadline(Deadline::after(timeout))
+    }
+
+    /// Binds `T` so each scope gets its own instance, e.g. per request or job.
+    /// Resolving it outside a scope panics.
+    #[track_caller]
+    pub fn bind_scoped<T, F>(&self, factory: F) -> &Self
+    where
+        T: Send + Sync + 'static,
+        F: Fn(&Container) -> Arc<T> + Send + Sync + 'static,
+    {
+        self.bind_with_lifetime::<T, F>(Lifetime::Scoped, factory)
+    }
+
+    /// Runs `hook` on every new scope, e.g. to pre-populate scoped state.
+    pub fn on_scope_created<F>(&self, hook: F) -> &Self
+    where
+        F: Fn(&Scope<'_>) + Send + Sync + 'static,
+    {
+        self.scope_hooks
+            .lock()
+            .unwrap()
+            .created
+            .push(Arc::new(hook));
+        self
+    }
+
+    /// Runs `hook` when a scope is dropped, before its cancellation token fires.
+    pub fn on_scope_disposed<F>(&self, hook: F) -> &Self
+    where
+        F: Fn(&Scope<'_>) + Send + Sync + 'static,
+    {
+        self.scope_hooks
+            .lock()
+            .unwrap()
+            .disposed
+            .push(Arc::new(hook));
+        self
+    }
+}
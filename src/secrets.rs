@@ -0,0 +1,214 @@
+use crate::container::Container;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// A secret value that never shows up in `Debug` output or panic messages.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Secret(String);
+
+impl Secret {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Secret(***)")
+    }
+}
+
+/// Where secrets come from. Sources are consulted in order by [`SecretStore`].
+pub trait SecretSource: Send + Sync {
+    fn fetch(&self, name: &str) -> Option<Secret>;
+}
+
+/// Reads `PREFIX` + upper-cased name from the process environment.
+pub struct EnvSecrets {
+    prefix: String,
+}
+
+impl EnvSecrets {
+    pub fn new(prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+        }
+    }
+}
+
+impl SecretSource for EnvSecrets {
+    fn fetch(&self, name: &str) -> Option<Secret> {
+        let key = format!(
+            "{}{}",
+            self.prefix,
+            name.to_uppercase().replace(['.', '-'], "_")
+        );
+        std::env::var(key).ok().map(Secret)
+    }
+}
+
+/// One file per secret in a directory, as mounted by Docker or Kubernetes.
+pub struct FileSecrets {
+    directory: PathBuf,
+}
+
+impl FileSecrets {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+}
+
+impl SecretSource for FileSecrets {
+    fn fetch(&self, name: &str) -> Option<Secret> {
+        if name.contains(['/', '\\']) || name.starts_with('.') {
+            return None;
+        }
+
+        let contents = std::fs::read_to_string(self.directory.join(name)).ok()?;
+        Some(Secret(contents.trim_end_matches(['\r', '\n']).to_string()))
+    }
+}
+
+/// HashiCorp Vault KV v2 backend. Secret names are `path#key`, or just
+/// `path` to read the `value` key.
+#[cfg(feature = "vault")]
+pub struct VaultSecrets {
+    address: String,
+    token: Secret,
+    mount: String,
+}
+
+#[cfg(feature = "vault")]
+impl VaultSecrets {
+    pub fn new(address: impl Into<String>, token: Secret, mount: impl Into<String>) -> Self {
+        Self {
+            address: address.into().trim_end_matches('/').to_string(),
+            token,
+            mount: mount.into(),
+        }
+    }
+}
+
+#[cfg(feature = "vault")]
+impl SecretSource for VaultSecrets {
+    fn fetch(&self, name: &str) -> Option<Secret> {
+        let (path, key) = name.split_once('#').unwrap_or((name, "value"));
+        let url = format!("{}/v1/{}/data/{}", self.address, self.mount, path);
+
+        let body: serde_json::Value = ureq::get(&url)
+            .set("X-Vault-Token", self.token.expose())
+            .call()
+            .ok()?
+            .into_json()
+            .ok()?;
+
+        body["data"]["data"][key].as_str().map(Secret::new)
+    }
+}
+
+type RotationHook = Arc<dyn Fn(&str, &Secret) + Send + Sync>;
+
+/// Injected entry point for credentials: caches lookups across sources and
+/// notifies listeners when a refreshed secret changed.
+pub struct SecretStore {
+    sources: Vec<Box<dyn SecretSource>>,
+    ttl: Option<Duration>,
+    cache: Mutex<HashMap<String, (Instant, Secret)>>,
+    hooks: Mutex<Vec<RotationHook>>,
+}
+
+impl SecretStore {
+    pub fn new() -> Self {
+        Self {
+            sources: Vec::new(),
+            ttl: None,
+            cache: Mutex::new(HashMap::new()),
+            hooks: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn with_source(mut self, source: impl SecretSource + 'static) -> Self {
+        self.sources.push(Box::new(source));
+        self
+    }
+
+    /// Cached secrets older than `ttl` are re-fetched on the next `get`.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    pub fn on_rotate<F>(&self, hook: F) -> &Self
+    where
+        F: Fn(&str, &Secret) + Send + Sync + 'static,
+    {
+        self.hooks.lock().unwrap().push(Arc::new(hook));
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<Secret> {
+        let cached = self.cache.lock().unwrap().get(name).cloned();
+
+        match cached {
+            Some((fetched, secret)) if self.ttl.is_none_or(|ttl| fetched.elapsed() < ttl) => {
+                Some(secret)
+            }
+            _ => self.refresh(name),
+        }
+    }
+
+    pub fn require(&self, name: &str) -> Secret {
+        self.get(name)
+            .unwrap_or_else(|| panic!("Missing secret: {name:?}"))
+    }
+
+    /// Re-fetches `name` from the sources, firing rotation hooks if it changed.
+    pub fn refresh(&self, name: &str) -> Option<Secret> {
+        let secret = self.sources.iter().find_map(|source| source.fetch(name))?;
+        let previous = self
+            .cache
+            .lock()
+            .unwrap()
+            .insert(name.to_string(), (Instant::now(), secret.clone()));
+
+        if previous.is_some_and(|(_, old)| old != secret) {
+            let hooks = self.hooks.lock().unwrap().clone();
+            for hook in hooks {
+                hook(name, &secret);
+            }
+        }
+
+        Some(secret)
+    }
+
+    pub fn forget(&self, name: &str) {
+        self.cache.lock().unwrap().remove(name);
+    }
+}
+
+impl Default for SecretStore {
+    fn default() -> Self {
+        Self::new().with_source(EnvSecrets::new(""))
+    }
+}
+
+impl Container {
+    pub fn set_secret_store(&self, store: SecretStore) -> &Self {
+        self.set_internal(Arc::new(store));
+        self
+    }
+
+    /// The configured [`SecretStore`], defaulting to environment variables.
+    pub fn secrets(&self) -> Arc<SecretStore> {
+        self.internal_or_insert_with(SecretStore::default)
+    }
+}
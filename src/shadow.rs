@@ -0,0 +1,131 @@
+use crate::container::Container;
+use std::fmt::Debug;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+type MismatchReporter = Arc<dyn Fn(&ShadowMismatch) + Send + Sync>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShadowMismatch {
+    pub service: &'static str,
+    pub primary: String,
+    /// `None` when the shadow implementation panicked.
+    pub shadow: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct ShadowStats {
+    pub calls: AtomicUsize,
+    pub mismatches: AtomicUsize,
+    pub panics: AtomicUsize,
+}
+
+/// Dark-launch wrapper: every call is served by the primary implementation
+/// and replayed against the shadow one, with differing results reported.
+pub struct Shadow<T: ?Sized> {
+    primary: Arc<T>,
+    shadow: Arc<T>,
+    stats: Arc<ShadowStats>,
+    reporter: MismatchReporter,
+}
+
+/// Default reporter: a `tracing` warning when that feature is enabled.
+/// Use [`Shadow::on_mismatch`] to collect mismatches some other way.
+fn trace_mismatch(mismatch: &ShadowMismatch) {
+    #[cfg(feature = "tracing")]
+    tracing::warn!(
+        target: "luminos_container",
+        service = mismatch.service,
+        primary = %mismatch.primary,
+        shadow = mismatch.shadow.as_deref().unwrap_or("<panicked>"),
+        "shadow implementation diverged"
+    );
+    #[cfg(not(feature = "tracing"))]
+    let _ = mismatch;
+}
+
+impl<T: ?Sized> Clone for Shadow<T> {
+    fn clone(&self) -> Self {
+        Self {
+            primary: self.primary.clone(),
+            shadow: self.shadow.clone(),
+            stats: self.stats.clone(),
+            reporter: self.reporter.clone(),
+        }
+    }
+}
+
+impl<T: ?Sized + 'static> Shadow<T> {
+    pub fn new(primary: Arc<T>, shadow: Arc<T>) -> Self {
+        Self {
+            primary,
+            shadow,
+            stats: Arc::new(ShadowStats::default()),
+            reporter: Arc::new(trace_mismatch),
+        }
+    }
+
+    pub fn on_mismatch<F>(mut self, reporter: F) -> Self
+    where
+        F: Fn(&ShadowMismatch) + Send + Sync + 'static,
+    {
+        self.reporter = Arc::new(reporter);
+        self
+    }
+
+    pub fn primary(&self) -> &Arc<T> {
+        &self.primary
+    }
+
+    pub fn stats(&self) -> &ShadowStats {
+        &self.stats
+    }
+
+    /// Runs `operation` on both implementations and returns the primary's
+    /// result. A panicking shadow is contained and counted.
+    pub fn call<R, F>(&self, operation: F) -> R
+    where
+        R: PartialEq + Debug,
+        F: Fn(&T) -> R,
+    {
+        let result = operation(&self.primary);
+        self.stats.calls.fetch_add(1, Ordering::Relaxed);
+
+        let shadow = catch_unwind(AssertUnwindSafe(|| operation(&self.shadow)));
+        let mismatch = match &shadow {
+            Ok(shadowed) if *shadowed == result => None,
+            Ok(shadowed) => Some(Some(format!("{shadowed:?}"))),
+            Err(_) => {
+                self.stats.panics.fetch_add(1, Ordering::Relaxed);
+                Some(None)
+            }
+        };
+
+        if let Some(shadow) = mismatch {
+            self.stats.mismatches.fetch_add(1, Ordering::Relaxed);
+            (self.reporter)(&ShadowMismatch {
+                service: std::any::type_name::<T>(),
+                primary: format!("{result:?}"),
+                shadow,
+            });
+        }
+
+        result
+    }
+}
+
+impl Container {
+    /// Pairs two [versioned](Container::bind_versioned) bindings of `T`,
+    /// serving `primary` while shadowing `candidate`. Both are built through
+    /// the regular pipeline, so decorators of `Arc<T>` wrap each of them.
+    pub fn resolve_shadow<T>(&self, primary: &str, candidate: &str) -> Shadow<T>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        Shadow::new(
+            self.resolve_version::<T>(primary),
+            self.resolve_version::<T>(candidate),
+        )
+    }
+}
@@ -0,0 +1,202 @@
+use crate::container::{Container, ProviderEntry};
+use crate::error::ContainerError;
+use crate::ordering::topological_order;
+use crate::provider_state::ProviderStateGuard;
+use luminos_contracts::container::{Contract, Injectable};
+use luminos_contracts::support::ServiceProvider;
+use std::any::TypeId;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+
+#[derive(Default)]
+struct SignalState {
+    triggered: bool,
+    wakers: Vec<Waker>,
+}
+
+/// Fires once when the container shuts down. Long-running services inject
+/// it and either `.await` a clone (e.g. in a `select!`) or poll
+/// [`ShutdownSignal::is_triggered`] between iterations of their loop.
+#[derive(Clone, Default)]
+pub struct ShutdownSignal {
+    state: Arc<(Mutex<SignalState>, Condvar)>,
+}
+
+impl ShutdownSignal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn trigger(&self) {
+        let (lock, signal) = &*self.state;
+        let wakers = {
+            let mut state = lock.lock().unwrap();
+            state.triggered = true;
+            std::mem::take(&mut state.wakers)
+        };
+        signal.notify_all();
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+
+    pub fn is_triggered(&self) -> bool {
+        self.state.0.lock().unwrap().triggered
+    }
+
+    /// Blocks until triggered or `timeout` elapses; returns whether it was triggered.
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        let (lock, signal) = &*self.state;
+        let (state, _) = signal
+            .wait_timeout_while(lock.lock().unwrap(), timeout, |s| !s.triggered)
+            .unwrap();
+        state.triggered
+    }
+
+    pub fn wait(&self) {
+        let (lock, signal) = &*self.state;
+        let _state = signal
+            .wait_while(lock.lock().unwrap(), |s| !s.triggered)
+            .unwrap();
+    }
+}
+
+impl Future for ShutdownSignal {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.state.0.lock().unwrap();
+
+        if state.triggered {
+            return Poll::Ready(());
+        }
+        if !state.wakers.iter().any(|w| w.will_wake(cx.waker())) {
+            state.wakers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+impl Injectable for ShutdownSignal {
+    fn __register<C: Contract>(container: &C) {
+        container.bind::<ShutdownSignal, _>(|_| Arc::new(ShutdownSignal::new()));
+    }
+}
+
+/// The shutdown counterpart of a provider's `boot`, for closing pools and
+/// stopping workers the provider started.
+pub trait Terminate: Send + Sync {
+    fn terminate(&self, container: &Container);
+}
+
+pub(crate) type TerminateHook = Arc<dyn Terminate>;
+
+struct TerminableProvider<P>(Arc<P>);
+
+impl<P: ServiceProvider<Container>> ServiceProvider<Container> for TerminableProvider<P> {
+    fn register(&self, container: &Container) {
+        self.0.register(container);
+    }
+
+    fn boot(&self, container: &Container) {
+        self.0.boot(container);
+    }
+}
+
+impl Container {
+    /// Adds a provider whose [`Terminate`] hook runs during [`Container::shutdown`].
+    #[track_caller]
+    pub fn add_terminable_provider<P>(&self, provider: P) -> &Self
+    where
+        P: ServiceProvider<Container> + Terminate + 'static,
+    {
+        let provider = Arc::new(provider);
+        let mut entry = ProviderEntry::new(None, Box::new(TerminableProvider(provider.clone())));
+        entry.terminate = Some(provider);
+        self.providers.lock().unwrap().push(entry);
+        self
+    }
+
+    /// Triggers the [`ShutdownSignal`], runs provider `terminate` hooks in
+    /// reverse boot order, saves persisted state, then drops cached
+    /// instances with consumers going before the services they depend on.
+    ///
+    /// If persisted state cannot be saved, nothing is dropped, so the save
+    /// can be retried with [`Container::save_persistent_state`]. The signal
+    /// itself stays cached and triggered.
+    pub fn shutdown(&self) -> Result<(), ContainerError> {
+        self.trigger_shutdown();
+
+        let providers = self.checkout_providers();
+        for entry in providers.iter().rev() {
+            if let Some(hook) = &entry.terminate {
+                let _state = ProviderStateGuard::enter(entry.state.clone());
+                hook.terminate(self);
+            }
+        }
+        drop(providers);
+
+        self.save_persistent_states().map_err(|(type_name, err)| {
+            ContainerError::PersistFailed {
+                type_name,
+                message: err.to_string(),
+            }
+        })?;
+
+        let signal = TypeId::of::<ShutdownSignal>();
+        let mut instances: Vec<_> = self
+            .instances
+            .snapshot()
+            .into_iter()
+            .filter(|(type_id, _)| *type_id != signal)
+            .collect();
+        for (type_id, _) in &instances {
+            self.instances.remove(type_id);
+        }
+
+        let factories = self.factories.lock().unwrap().clone();
+        let names: Vec<&'static str> = instances
+            .iter()
+            .map(|(type_id, _)| factories.get(type_id).map_or("", |b| b.type_name))
+            .collect();
+        let nodes: Vec<_> = {
+            let edges = self.dependencies.lock().unwrap();
+            names
+                .iter()
+                .map(|name| {
+                    let deps = edges
+                        .get(name)
+                        .map(|deps| {
+                            deps.iter()
+                                .copied()
+                                .filter(|dep| !dep.is_empty() && names.contains(dep))
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    (*name, deps)
+                })
+                .collect()
+        };
+
+        // Dependencies come first in topological order, so drop from the back.
+        let order = topological_order(&nodes).unwrap_or_else(|_| (0..nodes.len()).collect());
+        let mut slots: Vec<_> = instances.drain(..).map(Some).collect();
+        for index in order.into_iter().rev() {
+            drop(slots[index].take());
+        }
+        Ok(())
+    }
+
+    pub fn shutdown_signal(&self) -> ShutdownSignal {
+        (*self.resolve::<ShutdownSignal>()).clone()
+    }
+
+    /// Wakes everything waiting on the container's [`ShutdownSignal`].
+    pub fn trigger_shutdown(&self) -> &Self {
+        self.resolve::<ShutdownSignal>().trigger();
+        self
+    }
+}
@@ -0,0 +1,135 @@
+use crate::container::Container;
+use luminos_contracts::container::Contract;
+use std::fmt;
+use std::future::Future;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex, RwLock};
+use std::task::{Context, Poll, Waker};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RebuildError(pub String);
+
+impl fmt::Display for RebuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "background rebuild failed: {}", self.0)
+    }
+}
+
+impl std::error::Error for RebuildError {}
+
+#[derive(Default)]
+struct RebuildState {
+    result: Option<Result<Container, RebuildError>>,
+    waker: Option<Waker>,
+}
+
+/// A replacement container being built and booted on a background thread.
+/// Await it, or call [`Rebuild::wait`] from synchronous code.
+pub struct Rebuild {
+    state: Arc<(Mutex<RebuildState>, Condvar)>,
+}
+
+impl Rebuild {
+    pub fn wait(self) -> Result<Container, RebuildError> {
+        let (lock, ready) = &*self.state;
+        let mut state = ready
+            .wait_while(lock.lock().unwrap(), |s| s.result.is_none())
+            .unwrap();
+        state.result.take().unwrap()
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.state.0.lock().unwrap().result.is_some()
+    }
+}
+
+impl Future for Rebuild {
+    type Output = Result<Container, RebuildError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.0.lock().unwrap();
+
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Shared pointer to the live container that can be replaced atomically
+/// while requests keep resolving from whichever container they loaded.
+pub struct ContainerHandle {
+    current: RwLock<Arc<Container>>,
+}
+
+impl ContainerHandle {
+    pub fn new(container: Container) -> Self {
+        Self {
+            current: RwLock::new(Arc::new(container)),
+        }
+    }
+
+    pub fn load(&self) -> Arc<Container> {
+        self.current.read().unwrap().clone()
+    }
+
+    /// Installs `container` and returns the previous one, which stays alive
+    /// until in-flight users drop it.
+    pub fn swap(&self, container: Container) -> Arc<Container> {
+        std::mem::replace(&mut *self.current.write().unwrap(), Arc::new(container))
+    }
+
+    /// Builds a fresh container on a background thread: `configure` adds
+    /// providers and bindings (typically from new configuration), then it is booted.
+    pub fn rebuild_in_background<F>(&self, configure: F) -> Rebuild
+    where
+        F: FnOnce(&Container) + Send + 'static,
+    {
+        let state = Arc::new((Mutex::new(RebuildState::default()), Condvar::new()));
+        let shared = state.clone();
+
+        std::thread::spawn(move || {
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                let container = Container::new();
+                configure(&container);
+                container.boot();
+                container
+            }))
+            .map_err(|panic| {
+                let message = panic
+                    .downcast_ref::<String>()
+                    .cloned()
+                    .or_else(|| panic.downcast_ref::<&str>().map(|s| s.to_string()))
+                    .unwrap_or_else(|| "provider panicked".to_string());
+                RebuildError(message)
+            });
+
+            let (lock, ready) = &*shared;
+            let waker = {
+                let mut state = lock.lock().unwrap();
+                state.result = Some(result);
+                state.waker.take()
+            };
+            ready.notify_all();
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        });
+
+        Rebuild { state }
+    }
+
+    /// Rebuilds in the background and swaps the result in once it booted;
+    /// the live container is left untouched if the rebuild fails.
+    pub fn rebuild_and_swap<F>(&self, configure: F) -> Result<Arc<Container>, RebuildError>
+    where
+        F: FnOnce(&Container) + Send + 'static,
+    {
+        let container = self.rebuild_in_background(configure).wait()?;
+        Ok(self.swap(container))
+    }
+}
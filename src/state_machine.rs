@@ -0,0 +1,100 @@
+use crate::container::Container;
+use crate::scope::Scope;
+use luminos_contracts::container::Injectable;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Mutex;
+
+/// A transition handler, built anew inside a fresh [`Scope`] each time it
+/// runs so it can depend on scoped services and the state being left.
+pub trait Transition<S>: Injectable + Send + Sync + 'static {
+    fn apply(&self, from: &S, scope: &Scope<'_>) -> S;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransitionError<S> {
+    pub transition: &'static str,
+    pub from: S,
+}
+
+impl<S: fmt::Debug> fmt::Display for TransitionError<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "transition {} is not allowed from state {:?}",
+            self.transition, self.from
+        )
+    }
+}
+
+impl<S: fmt::Debug> std::error::Error for TransitionError<S> {}
+
+/// Current state plus the transitions allowed out of each state. Transitions
+/// are serialized: a handler must not fire another transition on the same
+/// machine.
+pub struct StateMachine<S> {
+    state: Mutex<S>,
+    allowed: HashMap<TypeId, Vec<S>>,
+}
+
+impl<S> StateMachine<S>
+where
+    S: Clone + PartialEq + Send + Sync + 'static,
+{
+    pub fn new(initial: S) -> Self {
+        Self {
+            state: Mutex::new(initial),
+            allowed: HashMap::new(),
+        }
+    }
+
+    /// Permits `T` to run from any of `from`. Transitions never declared here
+    /// are rejected.
+    pub fn allow<T: Transition<S>>(mut self, from: &[S]) -> Self {
+        self.allowed
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .extend_from_slice(from);
+        self
+    }
+
+    pub fn state(&self) -> S {
+        self.state.lock().unwrap().clone()
+    }
+
+    pub fn can<T: Transition<S>>(&self) -> bool {
+        let state = self.state.lock().unwrap();
+        self.permits::<T>(&state)
+    }
+
+    /// Builds a new `T`, runs it against the current state and stores the
+    /// state it returns. The scope sees the state being left as a local, as
+    /// do the handler's dependencies.
+    pub fn transition<T: Transition<S>>(
+        &self,
+        container: &Container,
+    ) -> Result<S, TransitionError<S>> {
+        let mut state = self.state.lock().unwrap();
+        if !self.permits::<T>(&state) {
+            return Err(TransitionError {
+                transition: std::any::type_name::<T>(),
+                from: state.clone(),
+            });
+        }
+
+        let scope = container.scope();
+        scope.insert_local(state.clone());
+        let handler = scope.enter(|_| container.build_uncached::<T>());
+        let next = handler.apply(&state, &scope);
+        *state = next.clone();
+
+        Ok(next)
+    }
+
+    fn permits<T: Transition<S>>(&self, state: &S) -> bool {
+        self.allowed
+            .get(&TypeId::of::<T>())
+            .is_some_and(|from| from.contains(state))
+    }
+}
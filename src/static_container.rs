@@ -0,0 +1,66 @@
+use crate::container::Container;
+
+/// A fixed set of services wired at compile time by [`static_container!`]:
+/// each service sits in its own `OnceLock` field and is reached through a
+/// generated accessor, so resolution involves no `TypeId` lookup and no lock
+/// once built. Anything outside the set comes from the dynamic container.
+pub trait StaticContainer {
+    fn dynamic(&self) -> &Container;
+}
+
+/// Declares a [`StaticContainer`]. Factories receive the static container
+/// and may call other accessors or fall back to `c.dynamic()`:
+///
+/// ```ignore
+/// static_container! {
+///     pub struct HotPath {
+///         config: Config => |c| (*c.dynamic().resolve::<Config>()).clone(),
+///         codec: Codec => |c| Codec::new(c.config()),
+///         router: Router => |c| Router::new(c.codec()),
+///     }
+/// }
+///
+/// let hot = HotPath::new(&container);
+/// let router = hot.router();
+/// ```
+#[macro_export]
+macro_rules! static_container {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($field:ident : $ty:ty => $factory:expr),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name<'c> {
+            dynamic: &'c $crate::Container,
+            $($field: ::std::sync::OnceLock<::std::sync::Arc<$ty>>,)*
+        }
+
+        impl<'c> $name<'c> {
+            $vis fn new(dynamic: &'c $crate::Container) -> Self {
+                Self {
+                    dynamic,
+                    $($field: ::std::sync::OnceLock::new(),)*
+                }
+            }
+
+            $(
+                $vis fn $field(&self) -> ::std::sync::Arc<$ty> {
+                    self.$field
+                        .get_or_init(|| {
+                            let factory: fn(&Self) -> $ty = $factory;
+                            ::std::sync::Arc::new(factory(self))
+                        })
+                        .clone()
+                }
+            )*
+        }
+
+        impl $crate::StaticContainer for $name<'_> {
+            fn dynamic(&self) -> &$crate::Container {
+                self.dynamic
+            }
+        }
+    };
+}
@@ -0,0 +1,202 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard};
+
+pub type Instance = Arc<dyn Any + Send + Sync>;
+
+/// Backing storage for resolved singleton instances.
+///
+/// Implementations handle their own synchronization; the container never
+/// holds a store lock while running user factories.
+pub trait InstanceStore: Send + Sync {
+    fn get(&self, type_id: &TypeId) -> Option<Instance>;
+    fn insert(&self, type_id: TypeId, instance: Instance);
+    fn remove(&self, type_id: &TypeId) -> Option<Instance>;
+    fn clear(&self);
+    fn len(&self) -> usize;
+    fn snapshot(&self) -> Vec<(TypeId, Instance)>;
+
+    /// Looks up several instances at once; stores should override this to
+    /// take their lock a single time.
+    fn get_many(&self, type_ids: &[TypeId]) -> Vec<Option<Instance>> {
+        type_ids.iter().map(|type_id| self.get(type_id)).collect()
+    }
+
+    fn contains(&self, type_id: &TypeId) -> bool {
+        self.get(type_id).is_some()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// An empty store of the same kind, for containers cloned from this one.
+    fn new_empty(&self) -> Box<dyn InstanceStore> {
+        Box::new(MemoryStore::new())
+    }
+}
+
+/// Hash map behind a read-write lock, so cache hits from many threads
+/// don't serialize on each other.
+#[derive(Default)]
+pub struct MemoryStore {
+    instances: RwLock<HashMap<TypeId, Instance>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Also read from `Debug`, which may run while a panic unwinds.
+    fn read(&self) -> RwLockReadGuard<'_, HashMap<TypeId, Instance>> {
+        self.instances
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl InstanceStore for MemoryStore {
+    fn get(&self, type_id: &TypeId) -> Option<Instance> {
+        self.read().get(type_id).cloned()
+    }
+
+    fn get_many(&self, type_ids: &[TypeId]) -> Vec<Option<Instance>> {
+        let instances = self.read();
+        type_ids
+            .iter()
+            .map(|id| instances.get(id).cloned())
+            .collect()
+    }
+
+    fn insert(&self, type_id: TypeId, instance: Instance) {
+        self.instances.write().unwrap().insert(type_id, instance);
+    }
+
+    fn remove(&self, type_id: &TypeId) -> Option<Instance> {
+        self.instances.write().unwrap().remove(type_id)
+    }
+
+    fn clear(&self) {
+        self.instances.write().unwrap().clear();
+    }
+
+    fn len(&self) -> usize {
+        self.read().len()
+    }
+
+    fn snapshot(&self) -> Vec<(TypeId, Instance)> {
+        self.read()
+            .iter()
+            .map(|(type_id, inst)| (*type_id, inst.clone()))
+            .collect()
+    }
+}
+
+/// In-memory store that remembers insertion order, so snapshots (and
+/// anything iterating them, like teardown) are reproducible across runs.
+#[derive(Default)]
+pub struct OrderedStore {
+    inner: RwLock<(Vec<TypeId>, HashMap<TypeId, Instance>)>,
+}
+
+impl OrderedStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Also read from `Debug`, which may run while a panic unwinds.
+    fn read(&self) -> RwLockReadGuard<'_, (Vec<TypeId>, HashMap<TypeId, Instance>)> {
+        self.inner.read().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl InstanceStore for OrderedStore {
+    fn get(&self, type_id: &TypeId) -> Option<Instance> {
+        self.read().1.get(type_id).cloned()
+    }
+
+    fn get_many(&self, type_ids: &[TypeId]) -> Vec<Option<Instance>> {
+        let inner = self.read();
+        type_ids.iter().map(|id| inner.1.get(id).cloned()).collect()
+    }
+
+    fn insert(&self, type_id: TypeId, instance: Instance) {
+        let mut inner = self.inner.write().unwrap();
+        if inner.1.insert(type_id, instance).is_none() {
+            inner.0.push(type_id);
+        }
+    }
+
+    fn remove(&self, type_id: &TypeId) -> Option<Instance> {
+        let mut inner = self.inner.write().unwrap();
+        let removed = inner.1.remove(type_id);
+        if removed.is_some() {
+            inner.0.retain(|id| id != type_id);
+        }
+        removed
+    }
+
+    fn clear(&self) {
+        let mut inner = self.inner.write().unwrap();
+        inner.0.clear();
+        inner.1.clear();
+    }
+
+    fn len(&self) -> usize {
+        self.read().0.len()
+    }
+
+    fn new_empty(&self) -> Box<dyn InstanceStore> {
+        Box::new(OrderedStore::new())
+    }
+
+    fn snapshot(&self) -> Vec<(TypeId, Instance)> {
+        let inner = self.read();
+        inner
+            .0
+            .iter()
+            .map(|type_id| (*type_id, inner.1[type_id].clone()))
+            .collect()
+    }
+}
+
+/// How often each type was resolved. Counting an already-seen type only
+/// takes the read lock.
+#[derive(Default)]
+pub(crate) struct ResolutionCounts {
+    counts: RwLock<HashMap<TypeId, AtomicUsize>>,
+}
+
+impl ResolutionCounts {
+    pub(crate) fn increment(&self, type_id: TypeId) {
+        if let Some(count) = self.read().get(&type_id) {
+            count.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+
+        self.counts
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(type_id)
+            .or_default()
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn contains(&self, type_id: &TypeId) -> bool {
+        self.read().contains_key(type_id)
+    }
+
+    pub(crate) fn snapshot(&self) -> HashMap<TypeId, usize> {
+        self.read()
+            .iter()
+            .map(|(type_id, count)| (*type_id, count.load(Ordering::Relaxed)))
+            .collect()
+    }
+
+    // Also read from `Debug`, which may run while a panic unwinds.
+    fn read(&self) -> RwLockReadGuard<'_, HashMap<TypeId, AtomicUsize>> {
+        self.counts.read().unwrap_or_else(PoisonError::into_inner)
+    }
+}
@@ -0,0 +1,121 @@
+use crate::container::{Binding, Container, Factory};
+use crate::lifetime::Lifetime;
+use crate::overrides::OverrideGuard;
+use std::any::TypeId;
+use std::panic::Location;
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+
+/// A set of replacement services applied together by
+/// [`Container::substitute_many`]. Usually built with [`substitutions!`](crate::substitutions).
+#[derive(Default)]
+pub struct Substitutions {
+    entries: Vec<(TypeId, Binding)>,
+}
+
+impl Substitutions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces `T` with `value`.
+    #[track_caller]
+    pub fn substitute<T>(self, value: T) -> Self
+    where
+        T: Send + Sync + 'static,
+    {
+        let value = Arc::new(value);
+        self.push::<T>(Arc::new(move |_| value.clone()))
+    }
+
+    /// Replaces the trait object binding `Tr`, as resolved by `resolve_trait`.
+    #[track_caller]
+    pub fn substitute_trait<Tr>(self, value: Arc<Tr>) -> Self
+    where
+        Tr: ?Sized + Send + Sync + 'static,
+    {
+        let value = Arc::new(value);
+        self.push::<Arc<Tr>>(Arc::new(move |_| value.clone()))
+    }
+
+    #[track_caller]
+    fn push<T: Send + Sync + 'static>(mut self, factory: Factory) -> Self {
+        self.entries.push((
+            TypeId::of::<T>(),
+            Binding {
+                factory,
+                type_name: std::any::type_name::<T>(),
+                implicit: false,
+                lifetime: Lifetime::Singleton,
+                location: Location::caller(),
+            },
+        ));
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Builds [`Substitutions`] from `Type => value` pairs; `dyn Trait` entries
+/// replace trait object bindings:
+///
+/// ```ignore
+/// let _guard = container.substitute_many(substitutions! {
+///     dyn Mailer => NullMailer::new(),
+///     Clock => MockClock::fixed(epoch),
+/// });
+/// ```
+#[macro_export]
+macro_rules! substitutions {
+    (@acc $subs:expr ;) => { $subs };
+    (@acc $subs:expr ; dyn $tr:path => $value:expr $(, $($rest:tt)*)?) => {
+        $crate::substitutions!(
+            @acc $subs.substitute_trait::<dyn $tr>(::std::sync::Arc::new($value)) ; $($($rest)*)?
+        )
+    };
+    (@acc $subs:expr ; $ty:ty => $value:expr $(, $($rest:tt)*)?) => {
+        $crate::substitutions!(@acc $subs.substitute::<$ty>($value) ; $($($rest)*)?)
+    };
+    ($($body:tt)*) => {
+        $crate::substitutions!(@acc $crate::Substitutions::new() ; $($body)*)
+    };
+}
+
+impl Container {
+    /// Applies every substitution at once and returns a guard that restores
+    /// the previous bindings and instances when dropped. Nothing is applied
+    /// if any substituted type is sealed.
+    pub fn substitute_many(&self, substitutions: Substitutions) -> OverrideGuard<'_> {
+        if self.frozen.load(Ordering::SeqCst) {
+            panic!("Cannot substitute bindings: container is frozen");
+        }
+        {
+            let sealed = self.sealed.lock().unwrap();
+            if let Some((_, binding)) = substitutions
+                .entries
+                .iter()
+                .find(|(type_id, _)| sealed.contains(type_id))
+            {
+                panic!("Cannot rebind sealed type: {:?}", binding.type_name);
+            }
+        }
+
+        let guard = self.push_overrides();
+        {
+            let mut factories = self.factories.lock().unwrap();
+            let factories = Arc::make_mut(&mut factories);
+            for (type_id, binding) in substitutions.entries {
+                self.instances.remove(&type_id);
+                factories.insert(type_id, binding);
+            }
+        }
+        self.invalidate_warm_pools();
+        guard
+    }
+}
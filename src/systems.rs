@@ -0,0 +1,150 @@
+use crate::container::Container;
+use crate::store::Instance;
+use luminos_contracts::container::{Contract, Injectable};
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// A value that can be fetched from the container as a system argument.
+///
+/// `last` is the per-system slot holding whatever was handed out on the
+/// previous tick, used for change detection.
+pub trait SystemParam: Sized {
+    fn fetch(last: &mut Option<Instance>, container: &Container) -> Self;
+}
+
+/// A resolved service plus whether it differs from the previous tick.
+pub struct Res<T> {
+    value: Arc<T>,
+    changed: bool,
+}
+
+impl<T> Res<T> {
+    pub fn is_changed(&self) -> bool {
+        self.changed
+    }
+
+    pub fn into_inner(self) -> Arc<T> {
+        self.value
+    }
+}
+
+impl<T> Deref for Res<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> SystemParam for Res<T>
+where
+    T: Injectable + Send + Sync + 'static,
+{
+    fn fetch(last: &mut Option<Instance>, container: &Container) -> Self {
+        let value = container.resolve::<T>();
+        let current: Instance = value.clone();
+        let changed = last
+            .as_ref()
+            .is_none_or(|prev| !Arc::ptr_eq(prev, &current));
+        *last = Some(current);
+
+        Self { value, changed }
+    }
+}
+
+pub trait System: Send {
+    fn run(&mut self, container: &Container);
+}
+
+pub trait IntoSystem<Params> {
+    fn into_system(self) -> Box<dyn System>;
+}
+
+pub struct FunctionSystem<F, Params> {
+    func: F,
+    slots: Vec<Option<Instance>>,
+    marker: PhantomData<fn() -> Params>,
+}
+
+macro_rules! impl_system {
+    ($($param:ident),*) => {
+        impl<F, $($param),*> System for FunctionSystem<F, ($($param,)*)>
+        where
+            F: FnMut($($param),*) + Send + 'static,
+            $($param: SystemParam + 'static,)*
+        {
+            #[allow(unused_variables, unused_mut)]
+            fn run(&mut self, container: &Container) {
+                let mut slots = self.slots.iter_mut();
+                (self.func)($($param::fetch(slots.next().unwrap(), container)),*);
+            }
+        }
+
+        impl<F, $($param),*> IntoSystem<($($param,)*)> for F
+        where
+            F: FnMut($($param),*) + Send + 'static,
+            $($param: SystemParam + 'static,)*
+        {
+            fn into_system(self) -> Box<dyn System> {
+                let arity = <[&str]>::len(&[$(stringify!($param)),*]);
+
+                Box::new(FunctionSystem {
+                    func: self,
+                    slots: vec![None; arity],
+                    marker: PhantomData::<fn() -> ($($param,)*)>,
+                })
+            }
+        }
+    };
+}
+
+impl_system!();
+impl_system!(P1);
+impl_system!(P1, P2);
+impl_system!(P1, P2, P3);
+impl_system!(P1, P2, P3, P4);
+impl_system!(P1, P2, P3, P4, P5);
+impl_system!(P1, P2, P3, P4, P5, P6);
+impl_system!(P1, P2, P3, P4, P5, P6, P7);
+impl_system!(P1, P2, P3, P4, P5, P6, P7, P8);
+
+/// An ordered list of systems run against the container once per tick.
+#[derive(Default)]
+pub struct Schedule {
+    systems: Vec<Box<dyn System>>,
+    ticks: u64,
+}
+
+impl Schedule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_system<P>(mut self, system: impl IntoSystem<P>) -> Self {
+        self.add_system(system);
+        self
+    }
+
+    pub fn add_system<P>(&mut self, system: impl IntoSystem<P>) -> &mut Self {
+        self.systems.push(system.into_system());
+        self
+    }
+
+    pub fn run(&mut self, container: &Container) {
+        for system in self.systems.iter_mut() {
+            system.run(container);
+        }
+        self.ticks += 1;
+    }
+
+    pub fn ticks(&self) -> u64 {
+        self.ticks
+    }
+}
+
+impl Container {
+    pub fn run_system<P>(&self, system: impl IntoSystem<P>) {
+        system.into_system().run(self);
+    }
+}
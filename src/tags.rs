@@ -0,0 +1,121 @@
+use crate::container::Container;
+use crate::store::Instance;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Turns a resolved instance into an `Arc<Arc<View>>` for a typed lookup.
+type View = Arc<dyn Fn(Instance) -> Instance + Send + Sync>;
+
+#[derive(Clone)]
+pub(crate) struct TaggedService {
+    type_id: TypeId,
+    type_name: &'static str,
+    views: HashMap<TypeId, View>,
+}
+
+impl TaggedService {
+    pub(crate) fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+}
+
+impl Container {
+    /// Adds the binding of `T` to `tag`. Tagging the same type twice is a no-op.
+    pub fn tag<T>(&self, tag: &str) -> &Self
+    where
+        T: Send + Sync + 'static,
+    {
+        self.tag_as::<T, T>(tag, |inst| inst)
+    }
+
+    /// Tags `T` and makes it available as `Tr` (usually a trait object)
+    /// through [`Container::resolve_tagged_as`].
+    pub fn tag_as<Tr, T>(&self, tag: &str, upcast: fn(Arc<T>) -> Arc<Tr>) -> &Self
+    where
+        Tr: ?Sized + Send + Sync + 'static,
+        T: Send + Sync + 'static,
+    {
+        let view: View =
+            Arc::new(move |inst| Arc::new(upcast(inst.downcast::<T>().unwrap())) as Instance);
+
+        let mut tags = self.tags.lock().unwrap();
+        let services = tags.entry(tag.to_string()).or_default();
+        let position = match services.iter().position(|s| s.type_id == TypeId::of::<T>()) {
+            Some(position) => position,
+            None => {
+                services.push(TaggedService {
+                    type_id: TypeId::of::<T>(),
+                    type_name: std::any::type_name::<T>(),
+                    views: HashMap::new(),
+                });
+                services.len() - 1
+            }
+        };
+        services[position].views.insert(TypeId::of::<Tr>(), view);
+        self
+    }
+
+    /// Type names tagged with `tag`, in tagging order.
+    pub fn tagged(&self, tag: &str) -> Vec<&'static str> {
+        self.tags
+            .lock()
+            .unwrap()
+            .get(tag)
+            .map(|services| services.iter().map(|s| s.type_name).collect())
+            .unwrap_or_default()
+    }
+
+    /// Every service tagged with `tag`, in tagging order. Tagged types must
+    /// be bound.
+    pub fn resolve_tagged(&self, tag: &str) -> Vec<Instance> {
+        let services: Vec<(TypeId, &'static str)> = self
+            .tags
+            .lock()
+            .unwrap()
+            .get(tag)
+            .map(|services| services.iter().map(|s| (s.type_id, s.type_name)).collect())
+            .unwrap_or_default();
+
+        services
+            .into_iter()
+            .map(|(type_id, type_name)| self.resolve_tagged_instance(tag, type_id, type_name))
+            .collect()
+    }
+
+    /// The services tagged with `tag` that were registered as `Tr`.
+    pub fn resolve_tagged_as<Tr>(&self, tag: &str) -> Vec<Arc<Tr>>
+    where
+        Tr: ?Sized + Send + Sync + 'static,
+    {
+        let services: Vec<(TypeId, &'static str, View)> = self
+            .tags
+            .lock()
+            .unwrap()
+            .get(tag)
+            .map(|services| {
+                services
+                    .iter()
+                    .filter_map(|s| {
+                        let view = s.views.get(&TypeId::of::<Tr>())?.clone();
+                        Some((s.type_id, s.type_name, view))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        services
+            .into_iter()
+            .map(|(type_id, type_name, view)| {
+                let inst = self.resolve_tagged_instance(tag, type_id, type_name);
+                view(inst).downcast_ref::<Arc<Tr>>().unwrap().clone()
+            })
+            .collect()
+    }
+
+    fn resolve_tagged_instance(&self, tag: &str, type_id: TypeId, type_name: &str) -> Instance {
+        self.resolve_instance(type_id).unwrap_or_else(|| {
+            panic!("Failed to resolve type: {type_name:?} (tagged {tag:?} but not bound)")
+        })
+    }
+}
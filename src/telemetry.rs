@@ -0,0 +1,149 @@
+use crate::container::Container;
+#[cfg(any(feature = "tracing", feature = "metrics"))]
+use crate::meter::Metrics;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+/// Per-binding share of resolutions that get traced and metered, from
+/// `0.0` to `1.0`.
+///
+/// Sampling is deterministic: a rate of `0.01` records exactly every 100th
+/// resolution of that type. Only consulted with the `tracing` or `metrics`
+/// feature.
+pub(crate) struct Sampling {
+    default_rate: RwLock<f64>,
+    rates: RwLock<HashMap<TypeId, f64>>,
+    counters: RwLock<HashMap<TypeId, Arc<AtomicU64>>>,
+    /// Type names seen so far, so cache hits don't lock `factories`.
+    #[cfg(any(feature = "tracing", feature = "metrics"))]
+    names: RwLock<HashMap<TypeId, &'static str>>,
+    /// The meter installed with `set_meter`.
+    #[cfg(any(feature = "tracing", feature = "metrics"))]
+    pub(crate) meter: RwLock<Option<Metrics>>,
+}
+
+impl Default for Sampling {
+    fn default() -> Self {
+        Self {
+            default_rate: RwLock::new(1.0),
+            rates: RwLock::new(HashMap::new()),
+            counters: RwLock::new(HashMap::new()),
+            #[cfg(any(feature = "tracing", feature = "metrics"))]
+            names: RwLock::new(HashMap::new()),
+            #[cfg(any(feature = "tracing", feature = "metrics"))]
+            meter: RwLock::new(None),
+        }
+    }
+}
+
+impl Sampling {
+    pub(crate) fn should_sample(&self, type_id: TypeId) -> bool {
+        let rate = self
+            .rates
+            .read()
+            .unwrap()
+            .get(&type_id)
+            .copied()
+            .unwrap_or_else(|| *self.default_rate.read().unwrap());
+
+        if rate >= 1.0 {
+            return true;
+        }
+        if rate <= 0.0 {
+            return false;
+        }
+
+        let counter = self.counters.read().unwrap().get(&type_id).cloned();
+        let counter = counter.unwrap_or_else(|| {
+            self.counters
+                .write()
+                .unwrap()
+                .entry(type_id)
+                .or_default()
+                .clone()
+        });
+
+        let n = counter.fetch_add(1, Ordering::Relaxed);
+        ((n + 1) as f64 * rate).floor() > (n as f64 * rate).floor()
+    }
+}
+
+impl Container {
+    /// Sets the sampling rate for resolutions of `T`, overriding the default.
+    pub fn set_sampling<T: ?Sized + 'static>(&self, rate: f64) -> &Self {
+        self.sampling
+            .rates
+            .write()
+            .unwrap()
+            .insert(TypeId::of::<T>(), rate.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Sampling rate for types without their own setting; starts at `1.0`.
+    pub fn set_default_sampling(&self, rate: f64) -> &Self {
+        *self.sampling.default_rate.write().unwrap() = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Traces and meters one resolution of `type_id`: a build when `built`
+    /// holds its duration, a cache hit otherwise.
+    #[cfg(any(feature = "tracing", feature = "metrics"))]
+    pub(crate) fn record_telemetry(
+        &self,
+        type_id: TypeId,
+        type_name: Option<&'static str>,
+        built: Option<Duration>,
+    ) {
+        if !self.sampling.should_sample(type_id) {
+            return;
+        }
+        let Some(type_name) = type_name.or_else(|| self.telemetry_name(type_id)) else {
+            return;
+        };
+
+        #[cfg(feature = "tracing")]
+        match built {
+            Some(elapsed) => tracing::trace!(
+                target: "luminos_container",
+                service = type_name,
+                elapsed_us = elapsed.as_micros() as u64,
+                "built service"
+            ),
+            None => tracing::trace!(
+                target: "luminos_container",
+                service = type_name,
+                "resolved cached service"
+            ),
+        }
+
+        let meter = self.sampling.meter.read().unwrap().clone();
+        if let Some(meter) = meter {
+            match built {
+                Some(elapsed) => {
+                    meter.counter("luminos_container.builds", 1);
+                    meter.histogram("luminos_container.build_seconds", elapsed.as_secs_f64());
+                }
+                None => meter.counter("luminos_container.cache_hits", 1),
+            }
+        }
+    }
+
+    /// The binding's type name, looked up in `factories` only the first time.
+    #[cfg(any(feature = "tracing", feature = "metrics"))]
+    fn telemetry_name(&self, type_id: TypeId) -> Option<&'static str> {
+        if let Some(name) = self.sampling.names.read().unwrap().get(&type_id) {
+            return Some(name);
+        }
+        let name = self
+            .factories
+            .lock()
+            .unwrap()
+            .get(&type_id)
+            .map(|b| b.type_name)?;
+        self.sampling.names.write().unwrap().insert(type_id, name);
+        Some(name)
+    }
+}
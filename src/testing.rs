@@ -0,0 +1,279 @@
+use crate::container::Container;
+use crate::ordering::topological_order;
+use crate::secrets::{Secret, SecretSource, SecretStore};
+use crate::store::{Instance, InstanceStore};
+use luminos_contracts::container::Contract;
+use luminos_contracts::support::ServiceProvider;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// A provider that, on top of the usual lifecycle, seeds data into the
+/// booted container for integration tests.
+pub trait FixtureProvider: ServiceProvider<Container> {
+    fn name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Names of fixtures that must be seeded before this one.
+    fn depends_on(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    fn seed(&self, container: &Container);
+}
+
+struct FixtureAdapter(Arc<dyn FixtureProvider>);
+
+impl ServiceProvider<Container> for FixtureAdapter {
+    fn register(&self, container: &Container) {
+        self.0.register(container);
+    }
+
+    fn boot(&self, container: &Container) {
+        self.0.boot(container);
+    }
+}
+
+#[derive(Default)]
+pub struct TestHarness {
+    container: Container,
+    fixtures: Vec<Arc<dyn FixtureProvider>>,
+}
+
+impl TestHarness {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_provider(self, provider: Box<dyn ServiceProvider<Container>>) -> Self {
+        self.container.add_provider(provider);
+        self
+    }
+
+    pub fn with_fixture(mut self, fixture: impl FixtureProvider + 'static) -> Self {
+        self.fixtures.push(Arc::new(fixture));
+        self
+    }
+
+    /// Boots every provider and fixture, then seeds fixtures in dependency order.
+    pub fn boot_with_fixtures(self) -> Container {
+        let nodes: Vec<_> = self
+            .fixtures
+            .iter()
+            .map(|f| (f.name(), f.depends_on()))
+            .collect();
+        let order = topological_order(&nodes)
+            .unwrap_or_else(|err| panic!("Failed to order fixtures: {err}"));
+
+        for &index in &order {
+            self.container
+                .add_provider(Box::new(FixtureAdapter(self.fixtures[index].clone())));
+        }
+
+        self.container.boot();
+
+        for &index in &order {
+            self.fixtures[index].seed(&self.container);
+        }
+
+        self.container
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Call {
+    pub target: &'static str,
+    pub method: &'static str,
+    pub detail: String,
+}
+
+/// Shared record of the calls made on test doubles, for asserting interactions.
+#[derive(Debug, Clone, Default)]
+pub struct CallLog {
+    calls: Arc<Mutex<Vec<Call>>>,
+}
+
+impl CallLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&self, target: &'static str, method: &'static str, detail: impl Into<String>) {
+        self.calls.lock().unwrap().push(Call {
+            target,
+            method,
+            detail: detail.into(),
+        });
+    }
+
+    pub fn calls(&self) -> Vec<Call> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    pub fn calls_to(&self, target: &str, method: &str) -> Vec<Call> {
+        self.calls
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|call| call.target == target && call.method == method)
+            .cloned()
+            .collect()
+    }
+
+    pub fn was_called(&self, target: &str, method: &str) -> bool {
+        !self.calls_to(target, method).is_empty()
+    }
+
+    pub fn clear(&self) {
+        self.calls.lock().unwrap().clear();
+    }
+}
+
+/// A [`SecretSource`] backed by a map, logging every lookup.
+#[derive(Clone, Default)]
+pub struct InMemorySecrets {
+    values: Arc<Mutex<HashMap<String, Secret>>>,
+    log: CallLog,
+}
+
+impl InMemorySecrets {
+    pub fn new(log: CallLog) -> Self {
+        Self {
+            values: Arc::default(),
+            log,
+        }
+    }
+
+    pub fn set(&self, name: &str, value: impl Into<String>) -> &Self {
+        self.values
+            .lock()
+            .unwrap()
+            .insert(name.to_string(), Secret::new(value));
+        self
+    }
+}
+
+impl SecretSource for InMemorySecrets {
+    fn fetch(&self, name: &str) -> Option<Secret> {
+        self.log.record("secrets", "fetch", name);
+        self.values.lock().unwrap().get(name).cloned()
+    }
+}
+
+/// An [`InstanceStore`] that keeps nothing, so every resolution rebuilds.
+#[derive(Debug, Default)]
+pub struct NullStore;
+
+impl InstanceStore for NullStore {
+    fn get(&self, _type_id: &TypeId) -> Option<Instance> {
+        None
+    }
+
+    fn insert(&self, _type_id: TypeId, _instance: Instance) {}
+
+    fn remove(&self, _type_id: &TypeId) -> Option<Instance> {
+        None
+    }
+
+    fn clear(&self) {}
+
+    fn len(&self) -> usize {
+        0
+    }
+
+    fn snapshot(&self) -> Vec<(TypeId, Instance)> {
+        Vec::new()
+    }
+
+    fn new_empty(&self) -> Box<dyn InstanceStore> {
+        Box::new(NullStore)
+    }
+}
+
+/// Wraps another store and logs cache traffic as `store.get` / `store.insert`
+/// / `store.remove` calls with the affected type id.
+pub struct RecordingStore<S> {
+    inner: S,
+    log: CallLog,
+}
+
+impl<S: InstanceStore> RecordingStore<S> {
+    pub fn new(inner: S, log: CallLog) -> Self {
+        Self { inner, log }
+    }
+}
+
+impl<S: InstanceStore> InstanceStore for RecordingStore<S> {
+    fn get(&self, type_id: &TypeId) -> Option<Instance> {
+        self.log.record("store", "get", format!("{type_id:?}"));
+        self.inner.get(type_id)
+    }
+
+    fn insert(&self, type_id: TypeId, instance: Instance) {
+        self.log.record("store", "insert", format!("{type_id:?}"));
+        self.inner.insert(type_id, instance);
+    }
+
+    fn remove(&self, type_id: &TypeId) -> Option<Instance> {
+        self.log.record("store", "remove", format!("{type_id:?}"));
+        self.inner.remove(type_id)
+    }
+
+    fn clear(&self) {
+        self.log.record("store", "clear", "");
+        self.inner.clear();
+    }
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn snapshot(&self) -> Vec<(TypeId, Instance)> {
+        self.inner.snapshot()
+    }
+}
+
+/// Wires the in-memory doubles into a container: secrets come from
+/// [`InMemorySecrets`] and the shared [`CallLog`] is available through
+/// `container.call_log()`.
+#[derive(Clone, Default)]
+pub struct TestingServiceProvider {
+    log: CallLog,
+    secrets: InMemorySecrets,
+}
+
+impl TestingServiceProvider {
+    pub fn new() -> Self {
+        let log = CallLog::new();
+        Self {
+            secrets: InMemorySecrets::new(log.clone()),
+            log,
+        }
+    }
+
+    pub fn with_secret(self, name: &str, value: impl Into<String>) -> Self {
+        self.secrets.set(name, value);
+        self
+    }
+
+    pub fn log(&self) -> CallLog {
+        self.log.clone()
+    }
+}
+
+impl ServiceProvider<Container> for TestingServiceProvider {
+    fn register(&self, container: &Container) {
+        container.set_secret_store(SecretStore::new().with_source(self.secrets.clone()));
+        container.set_internal(Arc::new(self.log.clone()));
+    }
+
+    fn boot(&self, _container: &Container) {}
+}
+
+impl Container {
+    /// The log registered by [`TestingServiceProvider`], or a fresh one.
+    pub fn call_log(&self) -> Arc<CallLog> {
+        self.internal_or_insert_with(CallLog::new)
+    }
+}
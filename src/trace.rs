@@ -0,0 +1,159 @@
+use crate::store::MemoryStore;
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::sync::{Arc, Once};
+
+/// An active scope's id and store; `None` hides the enclosing scope, see
+/// [`ScopeGuard::hide`].
+type ScopeEntry = Option<(u64, Arc<MemoryStore>)>;
+
+thread_local! {
+    static RESOLVING: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+    static BUILDING: RefCell<Vec<(TypeId, Option<String>)>> = const { RefCell::new(Vec::new()) };
+    static SCOPES: RefCell<Vec<ScopeEntry>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Keeps `name` on this thread's resolution chain until dropped.
+pub(crate) struct ResolvingGuard;
+
+impl ResolvingGuard {
+    pub(crate) fn enter(name: &'static str) -> Self {
+        RESOLVING.with(|chain| chain.borrow_mut().push(name));
+        Self
+    }
+}
+
+impl Drop for ResolvingGuard {
+    fn drop(&mut self) {
+        RESOLVING.with(|chain| chain.borrow_mut().pop());
+    }
+}
+
+/// Marks `type_id` as under construction on this thread, so a factory that
+/// (indirectly) asks for its own type fails fast instead of recursing forever.
+pub(crate) struct BuildingGuard;
+
+impl BuildingGuard {
+    pub(crate) fn enter(type_id: TypeId, name: &'static str) -> Self {
+        Self::enter_keyed(type_id, None, name)
+    }
+
+    /// Like `enter`, for the binding of `type_id` registered under `binding`;
+    /// each name is tracked separately from the others and the unnamed one.
+    pub(crate) fn enter_named(type_id: TypeId, binding: &str, name: &'static str) -> Self {
+        Self::enter_keyed(type_id, Some(binding.to_string()), name)
+    }
+
+    fn enter_keyed(type_id: TypeId, binding: Option<String>, name: &'static str) -> Self {
+        let cyclic = BUILDING.with(|building| {
+            building
+                .borrow()
+                .iter()
+                .any(|(id, named)| *id == type_id && *named == binding)
+        });
+        if cyclic {
+            let mut chain = resolution_chain();
+            if let Some(start) = chain.iter().position(|entry| *entry == name) {
+                chain.drain(..start);
+            }
+            chain.push(name);
+            panic!("Circular dependency detected: {}", chain.join(" -> "));
+        }
+
+        BUILDING.with(|building| building.borrow_mut().push((type_id, binding)));
+        Self
+    }
+}
+
+impl Drop for BuildingGuard {
+    fn drop(&mut self) {
+        BUILDING.with(|building| building.borrow_mut().pop());
+    }
+}
+
+pub(crate) fn is_building(type_id: TypeId) -> bool {
+    BUILDING.with(|building| {
+        building
+            .borrow()
+            .iter()
+            .any(|(id, named)| *id == type_id && named.is_none())
+    })
+}
+
+pub(crate) struct ScopeGuard;
+
+impl ScopeGuard {
+    pub(crate) fn enter(scope: u64, store: Arc<MemoryStore>) -> Self {
+        SCOPES.with(|scopes| scopes.borrow_mut().push(Some((scope, store))));
+        Self
+    }
+
+    /// Hides the active scope until dropped, so a shared instance built
+    /// meanwhile cannot capture its locals or scoped instances.
+    pub(crate) fn hide() -> Self {
+        SCOPES.with(|scopes| scopes.borrow_mut().push(None));
+        Self
+    }
+}
+
+impl Drop for ScopeGuard {
+    fn drop(&mut self) {
+        SCOPES.with(|scopes| scopes.borrow_mut().pop());
+    }
+}
+
+/// Types currently being built on this thread, outermost first.
+pub fn resolution_chain() -> Vec<&'static str> {
+    RESOLVING.with(|chain| chain.borrow().clone())
+}
+
+pub(crate) fn current_resolving() -> Option<&'static str> {
+    RESOLVING.with(|chain| chain.borrow().last().copied())
+}
+
+/// Id of the innermost scope active on this thread, if any.
+pub fn current_scope() -> Option<u64> {
+    SCOPES.with(|scopes| scopes.borrow().last().cloned().flatten().map(|(id, _)| id))
+}
+
+/// Instance cache of the innermost active scope, where scoped services live.
+pub(crate) fn current_scope_store() -> Option<Arc<MemoryStore>> {
+    SCOPES.with(|scopes| {
+        scopes
+            .borrow()
+            .last()
+            .cloned()
+            .flatten()
+            .map(|(_, store)| store)
+    })
+}
+
+/// True while a singleton build hides an active scope on this thread.
+pub(crate) fn scope_hidden() -> bool {
+    SCOPES.with(|scopes| matches!(scopes.borrow().last(), Some(None)))
+}
+
+/// Installs a panic hook that, before delegating to the previous hook,
+/// prints the active resolution chain and scope when a panic happens inside
+/// a factory, a scoped resolution or an invoked handler. Safe to call more than once.
+pub fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let previous = std::panic::take_hook();
+
+        std::panic::set_hook(Box::new(move |info| {
+            let chain = resolution_chain();
+            let scope = current_scope();
+
+            if !chain.is_empty() || scope.is_some() {
+                eprintln!("[container] panic while resolving: {}", chain.join(" -> "));
+                if let Some(scope) = scope {
+                    eprintln!("[container] active scope: #{scope}");
+                }
+            }
+
+            previous(info);
+        }));
+    });
+}
@@ -0,0 +1,86 @@
+use crate::container::Container;
+use luminos_contracts::container::{Contract, Injectable};
+use std::sync::Arc;
+
+impl Container {
+    /// Runs `I`'s `Injectable` registration now rather than on first
+    /// resolve, so trait objects it registers itself as (see
+    /// [`injectable_struct!`](crate::injectable_struct)) resolve immediately.
+    pub fn register<I>(&self) -> &Self
+    where
+        I: Injectable + Send + Sync + 'static,
+    {
+        let type_id = std::any::TypeId::of::<I>();
+        if self.factories.lock().unwrap().contains_key(&type_id) {
+            return self;
+        }
+
+        I::__register(self);
+        if let Some(binding) = Arc::make_mut(&mut self.factories.lock().unwrap()).get_mut(&type_id)
+        {
+            binding.implicit = true;
+        }
+        self
+    }
+
+    /// Binds the trait object `Tr` to the implementation `I`, sharing `I`'s
+    /// instance with direct resolutions of the concrete type:
+    ///
+    /// ```ignore
+    /// container.bind_trait::<dyn Repository, PostgresRepo>(|repo| repo);
+    /// ```
+    ///
+    /// `upcast` only performs the unsizing coercion, which stable Rust cannot
+    /// express generically.
+    #[track_caller]
+    pub fn bind_trait<Tr, I>(&self, upcast: fn(Arc<I>) -> Arc<Tr>) -> &Self
+    where
+        Tr: ?Sized + Send + Sync + 'static,
+        I: Injectable + Send + Sync + 'static,
+    {
+        self.bind_trait_with::<Tr, _>(move |c| upcast(c.resolve::<I>()))
+    }
+
+    /// Binds the trait object `Tr` to an arbitrary factory.
+    #[track_caller]
+    pub fn bind_trait_with<Tr, F>(&self, factory: F) -> &Self
+    where
+        Tr: ?Sized + Send + Sync + 'static,
+        F: Fn(&Container) -> Arc<Tr> + Send + Sync + 'static,
+    {
+        self.bind::<Arc<Tr>, _>(move |c| Arc::new(factory(c)));
+        self
+    }
+
+    pub fn get_trait<Tr>(&self) -> Option<Arc<Tr>>
+    where
+        Tr: ?Sized + Send + Sync + 'static,
+    {
+        if let Some(inst) = self.contextual::<Tr>() {
+            return Some(inst);
+        }
+
+        self.resolve_bound::<Arc<Tr>>()
+            .map(|inner| (*inner).clone())
+    }
+
+    pub fn resolve_trait<Tr>(&self) -> Arc<Tr>
+    where
+        Tr: ?Sized + Send + Sync + 'static,
+    {
+        self.get_trait::<Tr>().unwrap_or_else(|| {
+            panic!(
+                "Failed to resolve type: {:?} (no implementation bound)",
+                std::any::type_name::<Tr>()
+            )
+        })
+    }
+
+    /// Whether `Tr` is bound, or a deferred provider will bind it on first use.
+    pub fn has_trait<Tr>(&self) -> bool
+    where
+        Tr: ?Sized + 'static,
+    {
+        self.has::<Arc<Tr>>()
+    }
+}
@@ -0,0 +1,178 @@
+use crate::container::{Container, ProviderEntry};
+use crate::error::panic_message;
+use crate::manifest::ManifestConflict;
+use crate::ordering;
+use luminos_contracts::support::ServiceProvider;
+use std::error::Error;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+pub type ProviderError = Box<dyn Error + Send + Sync>;
+
+/// A provider whose hooks report failure instead of panicking. Add it with
+/// [`Container::add_fallible_provider`]; under plain `boot` an error still panics.
+pub trait FallibleServiceProvider: Send + Sync {
+    fn register(&self, container: &Container) -> Result<(), ProviderError>;
+
+    fn boot(&self, _container: &Container) -> Result<(), ProviderError> {
+        Ok(())
+    }
+}
+
+struct FallibleAdapter<P>(P);
+
+impl<P: FallibleServiceProvider> ServiceProvider<Container> for FallibleAdapter<P> {
+    fn register(&self, container: &Container) {
+        if let Err(err) = self.0.register(container) {
+            panic!("{err}");
+        }
+    }
+
+    fn boot(&self, container: &Container) {
+        if let Err(err) = self.0.boot(container) {
+            panic!("{err}");
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BootPhase {
+    Register,
+    Boot,
+}
+
+impl fmt::Display for BootPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Register => "register",
+            Self::Boot => "boot",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProviderFailure {
+    pub provider: String,
+    pub phase: BootPhase,
+    pub message: String,
+}
+
+/// Everything that went wrong during [`Container::try_boot`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BootReport {
+    pub conflicts: Vec<ManifestConflict>,
+    pub failures: Vec<ProviderFailure>,
+    /// Provider ordering or options validation errors.
+    pub errors: Vec<String>,
+}
+
+impl BootReport {
+    pub fn is_empty(&self) -> bool {
+        self.conflicts.is_empty() && self.failures.is_empty() && self.errors.is_empty()
+    }
+}
+
+impl fmt::Display for BootReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Boot failed:")?;
+        for conflict in &self.conflicts {
+            writeln!(f, "  {conflict}")?;
+        }
+        for failure in &self.failures {
+            writeln!(
+                f,
+                "  {} failed during {}: {}",
+                failure.provider, failure.phase, failure.message
+            )?;
+        }
+        for error in &self.errors {
+            writeln!(f, "  {error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for BootReport {}
+
+pub(crate) fn describe(entry: &ProviderEntry) -> String {
+    match entry.name {
+        Some(name) => name.to_string(),
+        None => format!("<anonymous> (added at {})", entry.location),
+    }
+}
+
+impl Container {
+    #[track_caller]
+    pub fn add_fallible_provider<P>(&self, provider: P) -> &Self
+    where
+        P: FallibleServiceProvider + 'static,
+    {
+        self.providers.lock().unwrap().push(ProviderEntry::new(
+            None,
+            Box::new(FallibleAdapter(provider)),
+        ));
+        self
+    }
+
+    /// Like `boot`, but collects every failing provider instead of stopping
+    /// at the first panic. Registration runs sequentially, and if any
+    /// provider fails to register none are booted.
+    pub fn try_boot(&self) -> Result<&Self, BootReport> {
+        let mut report = BootReport::default();
+        if let Err(conflicts) = self.check_manifests() {
+            report.conflicts = conflicts;
+            return Err(report);
+        }
+
+        let budget = self.start_budget();
+        self.environment();
+
+        let mut providers = self.checkout_providers();
+        providers.sort_by_key(|entry| std::cmp::Reverse(entry.priority));
+        if let Err(err) = ordering::order_providers(&mut providers) {
+            report.errors.push(err.to_string());
+            return Err(report);
+        }
+
+        for entry in providers.iter_mut() {
+            if let Err(payload) =
+                panic::catch_unwind(AssertUnwindSafe(|| self.register_provider(entry)))
+            {
+                report.failures.push(ProviderFailure {
+                    provider: describe(entry),
+                    phase: BootPhase::Register,
+                    message: panic_message(&*payload),
+                });
+            }
+        }
+        if let Err(errors) = self.validate_options() {
+            report.errors.push(errors.to_string());
+        }
+        if !report.is_empty() {
+            return Err(report);
+        }
+        self.refresh_capabilities(&providers);
+
+        for entry in providers.iter_mut() {
+            if let Err(payload) =
+                panic::catch_unwind(AssertUnwindSafe(|| self.boot_provider(entry)))
+            {
+                report.failures.push(ProviderFailure {
+                    provider: describe(entry),
+                    phase: BootPhase::Boot,
+                    message: panic_message(&*payload),
+                });
+            }
+        }
+
+        if let Some(budget) = budget {
+            self.finish_budget(budget, &providers);
+        }
+
+        if report.is_empty() {
+            Ok(self)
+        } else {
+            Err(report)
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use crate::container::Container;
+use crate::error::ContainerError;
+use crate::try_boot::BootReport;
+use luminos_contracts::container::{Contract, Injectable};
+use std::any::TypeId;
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+
+/// The fallible counterpart of `Contract`: every operation reports problems
+/// as values, so code embedding the container never panics through it.
+pub trait TryContract {
+    /// Binds `T`, failing instead of panicking when `T` is sealed or the
+    /// container is frozen.
+    fn try_bind<T, F>(&self, factory: F) -> Result<(), ContainerError>
+    where
+        T: Send + Sync + 'static,
+        F: Fn(&Container) -> Arc<T> + Send + Sync + 'static;
+
+    fn try_resolve<T>(&self) -> Result<Arc<T>, ContainerError>
+    where
+        T: Injectable + Send + Sync + 'static;
+
+    fn try_boot(&self) -> Result<(), BootReport>;
+}
+
+impl TryContract for Container {
+    fn try_bind<T, F>(&self, factory: F) -> Result<(), ContainerError>
+    where
+        T: Send + Sync + 'static,
+        F: Fn(&Container) -> Arc<T> + Send + Sync + 'static,
+    {
+        let type_name = std::any::type_name::<T>();
+        if self.frozen.load(Ordering::SeqCst) {
+            return Err(ContainerError::Frozen { type_name });
+        }
+        if self.sealed.lock().unwrap().contains(&TypeId::of::<T>()) {
+            return Err(ContainerError::Sealed {
+                type_name,
+                bound_at: self.binding_location::<T>(),
+            });
+        }
+
+        self.bind::<T, F>(factory);
+        Ok(())
+    }
+
+    fn try_resolve<T>(&self) -> Result<Arc<T>, ContainerError>
+    where
+        T: Injectable + Send + Sync + 'static,
+    {
+        Container::try_resolve::<T>(self)
+    }
+
+    fn try_boot(&self) -> Result<(), BootReport> {
+        Container::try_boot(self).map(|_| ())
+    }
+}
@@ -0,0 +1,55 @@
+use crate::container::Container;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+
+/// `Copy` values stored by type, each boxed once at bind time. Reads take
+/// the read lock, downcast and copy the value out.
+pub(crate) type ValueBindings = HashMap<TypeId, Arc<dyn Any + Send + Sync>>;
+
+impl Container {
+    /// Binds a small `Copy` value such as a flag or limit. Unlike service
+    /// bindings there is no factory, and reads return the value itself
+    /// rather than a cloned `Arc`.
+    pub fn bind_value<T>(&self, value: T) -> &Self
+    where
+        T: Copy + Send + Sync + 'static,
+    {
+        if self.frozen.load(Ordering::SeqCst) {
+            panic!(
+                "Cannot bind value: {:?} (container is frozen)",
+                std::any::type_name::<T>()
+            );
+        }
+        self.values
+            .write()
+            .unwrap()
+            .insert(TypeId::of::<T>(), Arc::new(value));
+        self
+    }
+
+    pub fn get_value<T>(&self) -> Option<T>
+    where
+        T: Copy + Send + Sync + 'static,
+    {
+        self.values
+            .read()
+            .unwrap()
+            .get(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_ref::<T>())
+            .copied()
+    }
+
+    pub fn resolve_value<T>(&self) -> T
+    where
+        T: Copy + Send + Sync + 'static,
+    {
+        self.get_value::<T>().unwrap_or_else(|| {
+            panic!(
+                "Failed to resolve value: {:?} (not bound with bind_value)",
+                std::any::type_name::<T>()
+            )
+        })
+    }
+}
@@ -0,0 +1,157 @@
+use crate::container::Container;
+use crate::scope::Scope;
+use crate::store::Instance;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+type VersionedFactory = Arc<dyn Fn(&Container) -> Instance + Send + Sync>;
+
+#[derive(Clone, Default)]
+pub(crate) struct VersionedBindings {
+    factories: Vec<(String, VersionedFactory)>,
+    instances: HashMap<String, Instance>,
+    selected: Option<String>,
+}
+
+impl VersionedBindings {
+    /// The same factories and selection with nothing built yet.
+    pub(crate) fn without_instances(&self) -> Self {
+        Self {
+            factories: self.factories.clone(),
+            instances: HashMap::new(),
+            selected: self.selected.clone(),
+        }
+    }
+}
+
+/// Scope-local override of the version resolved for `T`.
+struct VersionSelection<T: ?Sized> {
+    version: String,
+    marker: PhantomData<fn() -> Box<T>>,
+}
+
+impl Container {
+    /// Binds one version of `T` next to any others. `T` is usually a trait
+    /// object, so old and new implementations can coexist during a migration.
+    pub fn bind_versioned<T, F>(&self, version: &str, factory: F) -> &Self
+    where
+        T: ?Sized + Send + Sync + 'static,
+        F: Fn(&Container) -> Arc<T> + Send + Sync + 'static,
+    {
+        let factory: VersionedFactory = Arc::new(move |c| Arc::new(factory(c)) as Instance);
+        let mut versions = self.versions.lock().unwrap();
+        let entry = versions.entry(TypeId::of::<T>()).or_default();
+
+        entry.instances.remove(version);
+        entry.factories.retain(|(v, _)| v != version);
+        entry.factories.push((version.to_string(), factory));
+        self
+    }
+
+    /// Chooses the version [`Container::resolve_versioned`] returns, e.g. from configuration.
+    pub fn select_version<T>(&self, version: &str) -> &Self
+    where
+        T: ?Sized + 'static,
+    {
+        self.versions
+            .lock()
+            .unwrap()
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .selected = Some(version.to_string());
+        self
+    }
+
+    pub fn versions_of<T>(&self) -> Vec<String>
+    where
+        T: ?Sized + 'static,
+    {
+        self.versions
+            .lock()
+            .unwrap()
+            .get(&TypeId::of::<T>())
+            .map(|entry| entry.factories.iter().map(|(v, _)| v.clone()).collect())
+            .unwrap_or_default()
+    }
+
+    /// The selected version of `T`, or the most recently bound one when none was selected.
+    pub fn resolve_versioned<T>(&self) -> Arc<T>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        let version = {
+            let versions = self.versions.lock().unwrap();
+            versions.get(&TypeId::of::<T>()).and_then(|entry| {
+                entry
+                    .selected
+                    .clone()
+                    .or_else(|| entry.factories.last().map(|(v, _)| v.clone()))
+            })
+        };
+
+        match version {
+            Some(version) => self.resolve_version::<T>(&version),
+            None => panic!(
+                "Failed to resolve type: {:?} (no versions bound)",
+                std::any::type_name::<T>()
+            ),
+        }
+    }
+
+    /// `version` of `T`, built once with the decorators of `Arc<T>` applied.
+    pub fn resolve_version<T>(&self, version: &str) -> Arc<T>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        let type_id = TypeId::of::<T>();
+
+        let factory = {
+            let versions = self.versions.lock().unwrap();
+            let entry = versions.get(&type_id);
+
+            if let Some(inst) = entry.and_then(|e| e.instances.get(version)) {
+                return inst.downcast_ref::<Arc<T>>().unwrap().clone();
+            }
+
+            entry
+                .and_then(|e| e.factories.iter().find(|(v, _)| v == version))
+                .map(|(_, f)| f.clone())
+                .unwrap_or_else(|| {
+                    panic!(
+                        "Failed to resolve type: {:?} (version {version:?} is not bound)",
+                        std::any::type_name::<T>()
+                    )
+                })
+        };
+
+        let built = self.finish_build(TypeId::of::<Arc<T>>(), factory(self));
+        if let Some(entry) = self.versions.lock().unwrap().get_mut(&type_id) {
+            entry.instances.insert(version.to_string(), built.clone());
+        }
+        built.downcast_ref::<Arc<T>>().unwrap().clone()
+    }
+}
+
+impl Scope<'_> {
+    pub fn select_version<T>(&self, version: &str) -> &Self
+    where
+        T: ?Sized + 'static,
+    {
+        self.insert_local(VersionSelection::<T> {
+            version: version.to_string(),
+            marker: PhantomData,
+        })
+    }
+
+    pub fn resolve_versioned<T>(&self) -> Arc<T>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        match self.local::<VersionSelection<T>>() {
+            Some(selection) => self.container().resolve_version::<T>(&selection.version),
+            None => self.container().resolve_versioned::<T>(),
+        }
+    }
+}
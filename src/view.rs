@@ -0,0 +1,48 @@
+use crate::container::Container;
+use luminos_contracts::container::{Contract, Injectable};
+use std::any::TypeId;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// A handle that can only resolve a whitelist of types, for handing to
+/// plugins or third-party code. Allowed services are still built with full
+/// container access, so their own dependencies need not be whitelisted.
+#[derive(Clone)]
+pub struct RestrictedView<'a> {
+    container: &'a Container,
+    allowed: HashSet<TypeId>,
+}
+
+impl RestrictedView<'_> {
+    pub fn allows<T: ?Sized + 'static>(&self) -> bool {
+        self.allowed.contains(&TypeId::of::<T>())
+    }
+
+    pub fn get<T>(&self) -> Option<Arc<T>>
+    where
+        T: Injectable + Send + Sync + 'static,
+    {
+        self.allows::<T>().then(|| self.container.resolve::<T>())
+    }
+
+    pub fn resolve<T>(&self) -> Arc<T>
+    where
+        T: Injectable + Send + Sync + 'static,
+    {
+        self.get::<T>().unwrap_or_else(|| {
+            panic!(
+                "Failed to resolve type: {:?} (not allowed in this view)",
+                std::any::type_name::<T>()
+            )
+        })
+    }
+}
+
+impl Container {
+    pub fn restricted_view(&self, allow: &[TypeId]) -> RestrictedView<'_> {
+        RestrictedView {
+            container: self,
+            allowed: allow.iter().copied().collect(),
+        }
+    }
+}
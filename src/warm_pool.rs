@@ -0,0 +1,190 @@
+use crate::container::Container;
+use crate::lifetime::Lifetime;
+use crate::store::Instance;
+use crate::trace;
+use std::any::TypeId;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex, PoisonError, Weak};
+use std::time::Duration;
+
+pub(crate) type WarmPools = HashMap<TypeId, Arc<WarmPool>>;
+
+/// How long an idle refill thread sleeps before checking whether its
+/// container is still alive.
+const IDLE_CHECK: Duration = Duration::from_millis(250);
+
+/// Instances of one transient binding built ahead of time by a background
+/// thread, so a resolve can take one instead of running the factory.
+pub(crate) struct WarmPool {
+    size: usize,
+    ready: Mutex<VecDeque<Instance>>,
+    drained: Condvar,
+    retired: AtomicBool,
+    /// Bumped whenever the binding changes; instances built under an older
+    /// generation are dropped instead of pooled.
+    generation: AtomicU64,
+}
+
+impl WarmPool {
+    fn take(&self) -> Option<Instance> {
+        let instance = self
+            .ready
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .pop_front();
+        self.drained.notify_one();
+        instance
+    }
+
+    /// Drops every pre-built instance; the refill thread rebuilds them from
+    /// the current binding.
+    fn invalidate(&self) {
+        let mut ready = self.ready.lock().unwrap_or_else(PoisonError::into_inner);
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        ready.clear();
+        self.drained.notify_all();
+    }
+
+    pub(crate) fn retire(&self) {
+        self.retired.store(true, Ordering::Release);
+        self.drained.notify_all();
+    }
+
+    fn refill(&self, type_id: TypeId, container: Weak<Container>) {
+        loop {
+            {
+                let ready = self.ready.lock().unwrap_or_else(PoisonError::into_inner);
+                if ready.len() >= self.size {
+                    let _ = self.drained.wait_timeout(ready, IDLE_CHECK);
+                    if container.strong_count() == 0 || self.retired.load(Ordering::Acquire) {
+                        return;
+                    }
+                    continue;
+                }
+            }
+
+            if self.retired.load(Ordering::Acquire) {
+                return;
+            }
+            let Some(container) = container.upgrade() else {
+                return;
+            };
+            let generation = self.generation.load(Ordering::Acquire);
+            let lifetime = container
+                .factories
+                .lock()
+                .unwrap()
+                .get(&type_id)
+                .map(|binding| binding.lifetime);
+            if lifetime != Some(Lifetime::Transient) {
+                // Rebound with another lifetime: stay idle until it is
+                // transient again, the binding goes away or the container drops.
+                drop(container);
+                let ready = self.ready.lock().unwrap_or_else(PoisonError::into_inner);
+                let _ = self.drained.wait_timeout(ready, IDLE_CHECK);
+                continue;
+            }
+            let Some((instance, _)) = container.build_instance(type_id, false) else {
+                return;
+            };
+            drop(container);
+
+            let mut ready = self.ready.lock().unwrap_or_else(PoisonError::into_inner);
+            if self.generation.load(Ordering::Acquire) == generation {
+                ready.push_back(instance);
+            }
+        }
+    }
+}
+
+impl Container {
+    /// Keeps `size` instances of the transient binding `T` built in advance
+    /// on a background thread, handing them out on resolve and refilling as
+    /// they are taken. Rebinding `T` discards the instances built from the old
+    /// binding. The thread exits once the container is dropped.
+    ///
+    /// Pooled instances are built outside any scope, so resolves inside a
+    /// scope, or while contextual bindings exist, run the factory instead.
+    pub fn warm_pool<T>(self: &Arc<Self>, size: usize) -> &Arc<Self>
+    where
+        T: Send + Sync + 'static,
+    {
+        let type_id = TypeId::of::<T>();
+        if self.lifetime_of::<T>() != Some(Lifetime::Transient) {
+            panic!(
+                "Cannot pool type: {:?} (only transient bindings can be pooled)",
+                std::any::type_name::<T>()
+            );
+        }
+
+        let pool = Arc::new(WarmPool {
+            size: size.max(1),
+            ready: Mutex::new(VecDeque::new()),
+            drained: Condvar::new(),
+            retired: AtomicBool::new(false),
+            generation: AtomicU64::new(0),
+        });
+        if let Some(previous) = self
+            .warm_pools
+            .write()
+            .unwrap()
+            .insert(type_id, pool.clone())
+        {
+            previous.retire();
+        }
+
+        let container = Arc::downgrade(self);
+        std::thread::Builder::new()
+            .name(format!("warm-pool:{}", std::any::type_name::<T>()))
+            .spawn(move || pool.refill(type_id, container))
+            .expect("failed to spawn warm pool thread");
+        self
+    }
+
+    /// Number of pre-built instances of `T` currently waiting in its pool.
+    pub fn warm_pool_len<T>(&self) -> usize
+    where
+        T: Send + Sync + 'static,
+    {
+        self.warm_pools
+            .read()
+            .unwrap()
+            .get(&TypeId::of::<T>())
+            .map_or(0, |pool| pool.ready.lock().unwrap().len())
+    }
+
+    /// A pooled instance of `type_id`, unless a scope or contextual binding
+    /// could make a fresh build differ from one made on the refill thread.
+    pub(crate) fn take_warm(&self, type_id: TypeId) -> Option<Instance> {
+        let pools = self.warm_pools.read().unwrap();
+        if pools.is_empty()
+            || trace::current_scope_store().is_some()
+            || !self.contextual.lock().unwrap().is_empty()
+        {
+            return None;
+        }
+        let pool = pools.get(&type_id)?.clone();
+        drop(pools);
+
+        let instance = pool.take()?;
+        self.record_resolution(type_id);
+        Some(instance)
+    }
+
+    /// Discards the pooled instances of `type_id` after its binding changed.
+    pub(crate) fn invalidate_warm(&self, type_id: TypeId) {
+        let pool = self.warm_pools.read().unwrap().get(&type_id).cloned();
+        if let Some(pool) = pool {
+            pool.invalidate();
+        }
+    }
+
+    /// Discards every pooled instance, for changes spanning many bindings.
+    pub(crate) fn invalidate_warm_pools(&self) {
+        let pools: Vec<_> = self.warm_pools.read().unwrap().values().cloned().collect();
+        for pool in pools {
+            pool.invalidate();
+        }
+    }
+}
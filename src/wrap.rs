@@ -0,0 +1,47 @@
+/// Declares a transparent newtype around a foreign type so it can be
+/// injected like any `#[injectable]` service.
+///
+/// With `factory = path`, resolving the wrapper builds the inner value by
+/// calling `path(container)`, where `path` is generic over the contract:
+///
+/// ```ignore
+/// fn connect<C: Contract>(_: &C) -> PgPool { /* ... */ }
+///
+/// wrap_injectable!(pub MainPool => sqlx::PgPool, factory = connect);
+/// ```
+///
+/// Without a factory the wrapper has to be bound explicitly, typically with
+/// `container.bind::<MainPool, _>(|_| Arc::new(pool.into()))`.
+#[macro_export]
+macro_rules! wrap_injectable {
+    (
+        $(#[$meta:meta])*
+        $vis:vis $name:ident => $inner:ty $(, factory = $factory:path)? $(,)?
+    ) => {
+        $(#[$meta])*
+        $vis struct $name(pub $inner);
+
+        impl ::std::ops::Deref for $name {
+            type Target = $inner;
+
+            fn deref(&self) -> &$inner {
+                &self.0
+            }
+        }
+
+        impl ::std::convert::From<$inner> for $name {
+            fn from(inner: $inner) -> Self {
+                Self(inner)
+            }
+        }
+
+        impl $crate::Injectable for $name {
+            fn __register<C: $crate::__private::Contract>(container: &C) {
+                let _ = container;
+                $(
+                    container.bind::<$name, _>(|c| ::std::sync::Arc::new($name($factory(c))));
+                )?
+            }
+        }
+    };
+}
@@ -0,0 +1,31 @@
+use luminos_container::{Args, Container, Injectable};
+use luminos_contracts::container::Contract;
+use std::sync::Arc;
+
+struct Tenant(Option<String>);
+
+struct Report {
+    tenant: Arc<Tenant>,
+    title: String,
+}
+
+impl Injectable for Report {
+    fn __register<C: Contract>(_container: &C) {}
+}
+
+#[test]
+fn singleton_dependencies_do_not_capture_call_arguments() {
+    let container = Container::new();
+    container.bind::<Tenant, _>(|c| Arc::new(Tenant(c.arg::<String>("title"))));
+    container.bind_transient::<Report, _>(|c| {
+        Arc::new(Report {
+            tenant: c.resolve_optional::<Tenant>().unwrap(),
+            title: c.arg::<String>("title").unwrap(),
+        })
+    });
+
+    let report = container.resolve_with_args::<Report>(Args::new().with("title", "Q3".to_string()));
+
+    assert_eq!(report.title, "Q3");
+    assert_eq!(report.tenant.0, None);
+}
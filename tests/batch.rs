@@ -0,0 +1,40 @@
+use luminos_container::{Container, Injectable};
+use luminos_contracts::container::Contract;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct Tenant(&'static str);
+
+impl Injectable for Tenant {
+    fn __register<C: Contract>(container: &C) {
+        container.bind::<Tenant, _>(|_| Arc::new(Tenant("default")));
+    }
+}
+
+#[test]
+fn cached_batch_entries_fire_resolved_callbacks() {
+    let container = Container::new();
+    container.resolve::<Tenant>();
+
+    let fired = Arc::new(AtomicUsize::new(0));
+    let counter = fired.clone();
+    container.on_resolved(move |_, _, _| {
+        counter.fetch_add(1, Ordering::SeqCst);
+    });
+
+    let (tenant,) = container.resolve_batch::<(Tenant,)>();
+    assert_eq!(tenant.0, "default");
+    assert_eq!(fired.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn batches_inside_a_scope_see_its_locals() {
+    let container = Container::new();
+    container.resolve::<Tenant>();
+
+    let scope = container.scope();
+    scope.insert_local(Tenant("acme"));
+
+    let (tenant,) = scope.enter(|_| container.resolve_batch::<(Tenant,)>());
+    assert_eq!(tenant.0, "acme");
+}
@@ -0,0 +1,95 @@
+use luminos_container::{CancellationToken, Container, Deadline};
+use luminos_contracts::container::Contract;
+use std::sync::Arc;
+use std::time::Duration;
+
+struct Job {
+    token: CancellationToken,
+    deadline: Option<Arc<Deadline>>,
+}
+
+fn container() -> Container {
+    let container = Container::new();
+    container.bind_transient::<Job, _>(|c| {
+        Arc::new(Job {
+            token: (*c.resolve::<CancellationToken>()).clone(),
+            deadline: c.resolve_optional::<Deadline>(),
+        })
+    });
+    container
+}
+
+#[test]
+fn services_built_in_a_scope_see_its_cancellation() {
+    let container = container();
+
+    let scope = container.scope();
+    let job = scope
+        .enter(|_| container.resolve_optional::<Job>())
+        .unwrap();
+    assert!(!job.token.is_cancelled());
+
+    drop(scope);
+    assert!(job.token.is_cancelled());
+}
+
+#[test]
+fn services_built_in_a_scope_see_its_deadline() {
+    let container = container();
+
+    let scope = container.scope_with_timeout(Duration::from_secs(60));
+    let job = scope
+        .enter(|_| container.resolve_optional::<Job>())
+        .unwrap();
+
+    assert_eq!(job.deadline.as_deref(), scope.deadline().as_ref());
+    assert_eq!(job.token.deadline(), scope.deadline());
+}
+
+#[test]
+fn outside_a_scope_the_token_never_fires() {
+    let container = container();
+
+    let job = container.resolve_optional::<Job>().unwrap();
+    assert!(!job.token.is_cancelled());
+    assert!(job.deadline.is_none());
+}
+
+struct Poller {
+    token: CancellationToken,
+}
+
+#[test]
+fn singletons_first_built_in_a_scope_keep_the_container_token() {
+    let container = Container::new();
+    container.bind::<Poller, _>(|c| {
+        Arc::new(Poller {
+            token: (*c.resolve::<CancellationToken>()).clone(),
+        })
+    });
+
+    let scope = container.scope();
+    let poller = scope
+        .enter(|_| container.resolve_optional::<Poller>())
+        .unwrap();
+    drop(scope);
+
+    assert!(!poller.token.is_cancelled());
+}
+
+#[test]
+fn creation_hooks_see_the_deadline_token() {
+    let container = Container::new();
+    let seen = Arc::new(std::sync::Mutex::new(None));
+    let slot = seen.clone();
+    container.on_scope_created(move |scope| {
+        *slot.lock().unwrap() = Some(scope.cancellation_token());
+    });
+
+    let scope = container.scope_with_timeout(Duration::from_secs(60));
+    let token = seen.lock().unwrap().take().unwrap();
+
+    assert_eq!(token.deadline(), scope.deadline());
+    drop(scope);
+    assert!(token.is_cancelled());
+}
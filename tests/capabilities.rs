@@ -0,0 +1,25 @@
+use std::path::Path;
+
+#[test]
+fn every_cargo_feature_is_reported_as_a_capability() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let manifest = std::fs::read_to_string(root.join("Cargo.toml")).unwrap();
+    let source = std::fs::read_to_string(root.join("src/capabilities.rs")).unwrap();
+
+    let features = manifest
+        .lines()
+        .skip_while(|line| line.trim() != "[features]")
+        .skip(1)
+        .take_while(|line| !line.trim_start().starts_with('['))
+        .filter_map(|line| line.split_once('='))
+        .map(|(name, _)| name.trim())
+        .filter(|name| !name.is_empty() && *name != "default");
+
+    for feature in features {
+        let entry = format!("(\"{feature}\", cfg!(feature = \"{feature}\"))");
+        assert!(
+            source.contains(&entry),
+            "feature {feature:?} is missing from capabilities::FEATURES"
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use luminos_container::{Container, Lifetime, provides};
+use luminos_contracts::container::Contract;
+use luminos_contracts::support::ServiceProvider;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct RequestContext;
+
+struct Reporter {
+    _context: Arc<RequestContext>,
+}
+
+#[test]
+fn verify_reports_captives_without_caching_or_escapes() {
+    let built = Arc::new(AtomicUsize::new(0));
+    let container = Container::new();
+    container.bind_scoped::<RequestContext, _>(|_| Arc::new(RequestContext));
+    let counter = built.clone();
+    container.bind::<Reporter, _>(move |c| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Arc::new(Reporter {
+            _context: c.resolve_optional::<RequestContext>().unwrap(),
+        })
+    });
+
+    let report = container.verify().unwrap_err();
+    let captive = report.captive;
+    assert_eq!(captive.len(), 1);
+    assert!(report.unresolvable.is_empty());
+    assert_eq!(captive[0].lifetime, Lifetime::Scoped);
+
+    assert_eq!(built.load(Ordering::SeqCst), 1);
+    assert!(!container.is_resolved::<Reporter>());
+    assert!(container.scope_escapes().is_empty());
+}
+
+struct Mailer;
+
+struct MailServiceProvider;
+
+impl ServiceProvider<Container> for MailServiceProvider {
+    fn register(&self, container: &Container) {
+        container.bind::<Mailer, _>(|_| Arc::new(Mailer));
+    }
+
+    fn boot(&self, _container: &Container) {}
+}
+
+provides!(MailServiceProvider => Mailer);
+
+struct Newsletter {
+    _mailer: Arc<Mailer>,
+}
+
+struct Broken;
+
+#[test]
+fn verify_loads_deferred_providers_and_reports_failed_builds() {
+    let container = Container::new();
+    container.add_deferred_provider(MailServiceProvider);
+    container.bind::<Newsletter, _>(|c| {
+        Arc::new(Newsletter {
+            _mailer: c.resolve_optional::<Mailer>().unwrap(),
+        })
+    });
+    container.bind::<Broken, _>(|_| panic!("no credentials"));
+
+    let report = container.verify().unwrap_err();
+    assert!(report.captive.is_empty());
+    assert_eq!(
+        report.unresolvable,
+        vec![(
+            std::any::type_name::<Broken>(),
+            "no credentials".to_string()
+        )]
+    );
+
+    assert!(!container.is_resolved::<Mailer>());
+    assert!(container.resolve_optional::<Newsletter>().is_some());
+}
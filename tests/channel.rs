@@ -0,0 +1,32 @@
+use luminos_container::{Container, Receivers, Sender};
+use std::sync::Arc;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+#[test]
+fn a_full_subscriber_does_not_block_subscribe() {
+    let container = Container::new();
+    container.bind_channel::<u32>(1);
+    let sender = container.resolve_optional::<Sender<u32>>().unwrap();
+    let receivers: Arc<Receivers<u32>> = container.resolve_optional().unwrap();
+
+    let slow = receivers.subscribe();
+    assert_eq!(sender.send(1), Ok(1));
+
+    let (done, finished) = mpsc::channel();
+    let blocked = sender.clone();
+    thread::spawn(move || {
+        let _ = blocked.send(2);
+        done.send(()).unwrap();
+    });
+    thread::sleep(Duration::from_millis(20));
+
+    let late = receivers.subscribe();
+    assert_eq!(sender.receiver_count(), 2);
+
+    assert_eq!(slow.recv(), Ok(1));
+    assert_eq!(slow.recv(), Ok(2));
+    finished.recv_timeout(Duration::from_secs(5)).unwrap();
+    drop(late);
+}
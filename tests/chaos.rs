@@ -0,0 +1,46 @@
+#![cfg(feature = "chaos")]
+
+use luminos_container::{ChaosRule, Container, ContainerError, Injectable};
+use luminos_contracts::container::Contract;
+use std::sync::Arc;
+
+struct Database;
+
+impl Injectable for Database {
+    fn __register<C: Contract>(container: &C) {
+        container.bind::<Database, _>(|_| Arc::new(Database));
+    }
+}
+
+struct Repository {
+    _database: Arc<Database>,
+}
+
+impl Injectable for Repository {
+    fn __register<C: Contract>(container: &C) {
+        container.bind::<Repository, _>(|c| {
+            Arc::new(Repository {
+                _database: c.resolve::<Database>(),
+            })
+        });
+    }
+}
+
+#[test]
+fn injected_construction_fault_is_reported_by_try_resolve() {
+    let container = Container::new();
+    let chaos = container.chaos();
+    chaos.set_rule::<Database>(ChaosRule::failing(1.0));
+    chaos.enable();
+
+    let error = container.try_resolve::<Repository>().err().unwrap();
+    assert_eq!(
+        error,
+        ContainerError::FaultInjected {
+            type_name: std::any::type_name::<Database>(),
+        }
+    );
+
+    chaos.disable();
+    assert!(container.try_resolve::<Repository>().is_ok());
+}
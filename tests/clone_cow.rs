@@ -0,0 +1,52 @@
+use luminos_container::Container;
+use std::sync::Arc;
+
+trait Handler: Send + Sync {
+    fn name(&self) -> &'static str;
+}
+
+struct Audit;
+
+impl Handler for Audit {
+    fn name(&self) -> &'static str {
+        "audit"
+    }
+}
+
+struct Pool(&'static str);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct MaxConnections(u32);
+
+#[test]
+fn clones_resolve_every_kind_of_binding_like_the_original() {
+    let container = Container::deterministic();
+    container.bind_named::<Pool, _>("read", |_| Arc::new(Pool("replica")));
+    container.bind_many::<dyn Handler, _>(|_| Arc::new(Audit));
+    container.bind_versioned::<dyn Handler, _>("v2", |_| Arc::new(Audit));
+    container.bind_value(MaxConnections(8));
+    container.bind_trait_with::<dyn Handler, _>(|_| Arc::new(Audit));
+    container.tag::<Arc<dyn Handler>>("handlers");
+
+    let clone = container.clone_cow();
+
+    assert_eq!(clone.resolve_named::<Pool>("read").0, "replica");
+    assert_eq!(clone.resolve_all::<dyn Handler>().len(), 1);
+    assert_eq!(clone.resolve_versioned::<dyn Handler>().name(), "audit");
+    assert_eq!(clone.get_value::<MaxConnections>(), Some(MaxConnections(8)));
+    assert_eq!(clone.tagged("handlers"), container.tagged("handlers"));
+}
+
+#[test]
+fn clones_do_not_share_named_instances() {
+    let container = Container::new();
+    container.bind_named::<Pool, _>("read", |_| Arc::new(Pool("replica")));
+
+    let original = container.resolve_named::<Pool>("read");
+    let clone = container.clone_cow();
+
+    assert!(!Arc::ptr_eq(
+        &original,
+        &clone.resolve_named::<Pool>("read")
+    ));
+}
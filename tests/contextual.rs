@@ -0,0 +1,65 @@
+use luminos_container::{Container, Injectable};
+use luminos_contracts::container::Contract;
+use std::sync::Arc;
+
+trait Storage: Send + Sync {
+    fn name(&self) -> &'static str;
+}
+
+struct LocalStorage;
+
+impl Storage for LocalStorage {
+    fn name(&self) -> &'static str {
+        "local"
+    }
+}
+
+struct S3Storage;
+
+impl Storage for S3Storage {
+    fn name(&self) -> &'static str {
+        "s3"
+    }
+}
+
+impl Injectable for S3Storage {
+    fn __register<C: Contract>(container: &C) {
+        container.bind::<S3Storage, _>(|_| Arc::new(S3Storage));
+    }
+}
+
+struct ReportService {
+    storage: Arc<dyn Storage>,
+}
+
+struct InvoiceService {
+    storage: Arc<dyn Storage>,
+}
+
+#[test]
+fn trait_contextual_binding_applies_to_the_consumer_only() {
+    let container = Container::new();
+    container.bind_trait_with::<dyn Storage, _>(|_| Arc::new(LocalStorage));
+    container.bind::<ReportService, _>(|c| {
+        Arc::new(ReportService {
+            storage: c.resolve_trait::<dyn Storage>(),
+        })
+    });
+    container.bind::<InvoiceService, _>(|c| {
+        Arc::new(InvoiceService {
+            storage: c.resolve_trait::<dyn Storage>(),
+        })
+    });
+
+    container
+        .when::<ReportService>()
+        .needs::<dyn Storage>()
+        .give(|c| c.resolve::<S3Storage>());
+
+    let reports = container.resolve_optional::<ReportService>().unwrap();
+    let invoices = container.resolve_optional::<InvoiceService>().unwrap();
+
+    assert_eq!(reports.storage.name(), "s3");
+    assert_eq!(invoices.storage.name(), "local");
+    assert_eq!(container.resolve_trait::<dyn Storage>().name(), "local");
+}
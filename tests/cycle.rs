@@ -0,0 +1,56 @@
+use luminos_container::{Container, Injectable};
+use luminos_contracts::container::Contract;
+use std::any::type_name;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::sync::Arc;
+
+struct Orders {
+    _customers: Arc<Customers>,
+}
+
+impl Injectable for Orders {
+    fn __register<C: Contract>(container: &C) {
+        container.bind::<Orders, _>(|c| {
+            Arc::new(Orders {
+                _customers: c.resolve::<Customers>(),
+            })
+        });
+    }
+}
+
+struct Customers {
+    _orders: Arc<Orders>,
+}
+
+impl Injectable for Customers {
+    fn __register<C: Contract>(container: &C) {
+        container.bind::<Customers, _>(|c| {
+            Arc::new(Customers {
+                _orders: c.resolve::<Orders>(),
+            })
+        });
+    }
+}
+
+#[test]
+fn cycles_panic_with_the_chain() {
+    let container = Container::new();
+    let payload = catch_unwind(AssertUnwindSafe(|| {
+        container.resolve::<Orders>();
+    }))
+    .unwrap_err();
+    let message = payload
+        .downcast_ref::<String>()
+        .cloned()
+        .unwrap_or_default();
+
+    let chain = format!(
+        "{orders} -> {customers} -> {orders}",
+        orders = type_name::<Orders>(),
+        customers = type_name::<Customers>(),
+    );
+    assert!(
+        message.contains(&format!("Circular dependency detected: {chain}")),
+        "{message}"
+    );
+}
@@ -0,0 +1,126 @@
+use luminos_container::{Container, provides};
+use luminos_contracts::container::Contract;
+use luminos_contracts::support::ServiceProvider;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+trait Cache: Send + Sync {
+    fn name(&self) -> &'static str;
+}
+
+struct RedisCache;
+
+impl Cache for RedisCache {
+    fn name(&self) -> &'static str {
+        "redis"
+    }
+}
+
+struct CacheServiceProvider;
+
+impl ServiceProvider<Container> for CacheServiceProvider {
+    fn register(&self, container: &Container) {
+        thread::sleep(Duration::from_millis(20));
+        container.bind::<RedisCache, _>(|_| Arc::new(RedisCache));
+        container.bind_trait_with::<dyn Cache, _>(|c| c.resolve_optional::<RedisCache>().unwrap());
+    }
+
+    fn boot(&self, _container: &Container) {}
+}
+
+provides!(CacheServiceProvider => RedisCache, dyn Cache);
+
+#[test]
+fn trait_services_load_their_deferred_provider() {
+    let container = Container::new();
+    container.add_deferred_provider(CacheServiceProvider);
+
+    assert!(container.has_trait::<dyn Cache>());
+    assert_eq!(container.resolve_trait::<dyn Cache>().name(), "redis");
+}
+
+#[test]
+fn concurrent_resolves_wait_for_the_provider_to_register() {
+    let container = Container::new();
+    container.add_deferred_provider(CacheServiceProvider);
+
+    thread::scope(|s| {
+        let handles: Vec<_> = (0..4)
+            .map(|_| s.spawn(|| container.resolve_trait::<dyn Cache>().name()))
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), "redis");
+        }
+    });
+}
+
+struct Session;
+
+struct SessionStore;
+
+struct SessionServiceProvider {
+    saw_session: Arc<AtomicBool>,
+}
+
+impl ServiceProvider<Container> for SessionServiceProvider {
+    fn register(&self, container: &Container) {
+        container.bind::<SessionStore, _>(|_| Arc::new(SessionStore));
+        let session = container.resolve_optional::<Session>();
+        self.saw_session.store(session.is_some(), Ordering::SeqCst);
+        container.bind::<Session, _>(|_| Arc::new(Session));
+    }
+
+    fn boot(&self, _container: &Container) {}
+}
+
+provides!(SessionServiceProvider => Session, SessionStore);
+
+#[test]
+fn providers_can_resolve_their_own_services_while_registering() {
+    let saw_session = Arc::new(AtomicBool::new(true));
+    let container = Container::new();
+    container.add_deferred_provider(SessionServiceProvider {
+        saw_session: saw_session.clone(),
+    });
+
+    assert!(container.resolve_optional::<SessionStore>().is_some());
+    assert!(!saw_session.load(Ordering::SeqCst));
+    assert!(container.resolve_optional::<Session>().is_some());
+}
+
+struct Queue;
+
+struct FlakyServiceProvider {
+    attempts: Arc<AtomicUsize>,
+}
+
+impl ServiceProvider<Container> for FlakyServiceProvider {
+    fn register(&self, container: &Container) {
+        if self.attempts.fetch_add(1, Ordering::SeqCst) > 0 {
+            container.bind::<Queue, _>(|_| Arc::new(Queue));
+        }
+    }
+
+    fn boot(&self, _container: &Container) {}
+}
+
+provides!(FlakyServiceProvider => Queue);
+
+#[test]
+fn a_provider_that_fails_to_load_stays_deferred() {
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let container = Container::new();
+    container.add_deferred_provider(FlakyServiceProvider {
+        attempts: attempts.clone(),
+    });
+
+    let first = catch_unwind(AssertUnwindSafe(|| container.resolve_optional::<Queue>()));
+    assert!(first.is_err());
+    assert!(container.has::<Queue>());
+
+    assert!(container.resolve_optional::<Queue>().is_some());
+    assert_eq!(attempts.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,24 @@
+use luminos_container::Container;
+use luminos_contracts::container::Contract;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn dry_run_does_not_leak_provider_state_into_boot() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let container = Container::new();
+    let booted = seen.clone();
+    container.provide(
+        |c| *c.provider_state::<usize>().lock().unwrap() += 1,
+        move |c| {
+            booted
+                .lock()
+                .unwrap()
+                .push(*c.provider_state::<usize>().lock().unwrap())
+        },
+    );
+
+    assert!(container.dry_run_boot().is_ok());
+    container.boot();
+
+    assert_eq!(*seen.lock().unwrap(), vec![1]);
+}
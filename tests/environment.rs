@@ -0,0 +1,43 @@
+use luminos_container::{Container, Environment, Injectable};
+use luminos_contracts::container::Contract;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+struct Mailer {
+    environment: Arc<Environment>,
+}
+
+impl Injectable for Mailer {
+    fn __register<C: Contract>(container: &C) {
+        container.bind::<Mailer, _>(|c| {
+            Arc::new(Mailer {
+                environment: c.resolve::<Environment>(),
+            })
+        });
+    }
+}
+
+fn environment(name: &str) -> Environment {
+    Environment::from_vars(HashMap::from([(
+        Environment::NAME_VAR.to_string(),
+        name.to_string(),
+    )]))
+}
+
+#[test]
+fn services_can_inject_the_environment() {
+    let container = Container::new();
+    container.set_environment(environment("testing"));
+
+    assert!(container.resolve::<Mailer>().environment.is_testing());
+}
+
+#[test]
+fn set_environment_replaces_a_resolved_environment() {
+    let container = Container::new();
+    container.set_environment(environment("testing"));
+    assert!(container.resolve::<Environment>().is_testing());
+
+    container.set_environment(environment("production"));
+    assert!(container.resolve::<Environment>().is_production());
+}
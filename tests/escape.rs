@@ -0,0 +1,47 @@
+use luminos_container::Container;
+use luminos_contracts::container::Contract;
+use std::sync::Arc;
+
+struct RequestContext;
+
+struct Reporter {
+    _context: Arc<RequestContext>,
+}
+
+fn container() -> Container {
+    let container = Container::new();
+    container.bind_scoped::<RequestContext, _>(|_| Arc::new(RequestContext));
+    container.bind_transient::<Reporter, _>(|c| {
+        Arc::new(Reporter {
+            _context: c.resolve_optional::<RequestContext>().unwrap(),
+        })
+    });
+    container
+}
+
+/// Keeps a consumer built in a scope alive past the scope's end.
+fn capture(container: &Container) -> Arc<Reporter> {
+    let scope = container.scope();
+    scope
+        .enter(|_| container.resolve_optional::<Reporter>())
+        .unwrap()
+}
+
+#[test]
+fn escapes_are_not_tracked_by_default() {
+    let container = container();
+    let _reporter = capture(&container);
+
+    assert!(container.scope_escapes().is_empty());
+}
+
+#[test]
+fn escapes_are_collected_once_enabled() {
+    let container = container();
+    container.audit_scope_escapes(true);
+    let _reporter = capture(&container);
+
+    let escapes = container.scope_escapes();
+    assert_eq!(escapes.len(), 1);
+    assert!(escapes[0].service.ends_with("RequestContext"));
+}
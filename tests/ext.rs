@@ -0,0 +1,34 @@
+use luminos_container::{Container, ContractExt, Injectable};
+use luminos_contracts::container::Contract;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::sync::Arc;
+
+struct Clock(&'static str);
+
+impl Injectable for Clock {
+    fn __register<C: Contract>(_container: &C) {}
+}
+
+struct Broken;
+
+impl Injectable for Broken {
+    fn __register<C: Contract>(container: &C) {
+        container.bind::<Broken, _>(|_| panic!("broken factory"));
+    }
+}
+
+#[test]
+fn get_or_falls_back_when_nothing_provides_the_type() {
+    let container = Container::new();
+    assert_eq!(container.get_or(Arc::new(Clock("default"))).0, "default");
+
+    container.bind::<Clock, _>(|_| Arc::new(Clock("system")));
+    assert_eq!(container.get_or(Arc::new(Clock("default"))).0, "system");
+}
+
+#[test]
+fn get_or_does_not_swallow_factory_panics() {
+    let container = Container::new();
+    let result = catch_unwind(AssertUnwindSafe(|| container.get_or(Arc::new(Broken))));
+    assert!(result.is_err());
+}
@@ -0,0 +1,50 @@
+use luminos_container::Container;
+use luminos_contracts::container::Contract;
+use std::sync::Arc;
+
+struct Pool;
+
+struct Handler;
+
+struct Reporter;
+
+fn base() -> Container {
+    let container = Container::new();
+    container.bind::<Pool, _>(|_| Arc::new(Pool));
+    container
+}
+
+#[test]
+fn fingerprint_covers_named_multi_tagged_and_contextual_bindings() {
+    let plain = base().graph_fingerprint();
+
+    let named = base();
+    named.bind_named::<Pool, _>("read", |_| Arc::new(Pool));
+    assert!(named.graph_snapshot().contains("named"));
+
+    let multi = base();
+    multi.bind_many::<Handler, _>(|_| Arc::new(Handler));
+
+    let tagged = base();
+    tagged.tag::<Pool>("storage");
+
+    let contextual = base();
+    contextual
+        .when::<Reporter>()
+        .needs::<Pool>()
+        .give(|_| Arc::new(Pool));
+
+    let fingerprints = [
+        plain,
+        named.graph_fingerprint(),
+        multi.graph_fingerprint(),
+        tagged.graph_fingerprint(),
+        contextual.graph_fingerprint(),
+    ];
+    for (i, a) in fingerprints.iter().enumerate() {
+        for b in &fingerprints[i + 1..] {
+            assert_ne!(a, b);
+        }
+    }
+    assert_eq!(plain, base().graph_fingerprint());
+}
@@ -0,0 +1,67 @@
+use luminos_container::Container;
+use luminos_contracts::container::Contract;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct Settings(usize);
+
+struct Janitor;
+
+#[test]
+fn forgotten_instances_are_rebuilt_from_the_same_binding() {
+    let built = Arc::new(AtomicUsize::new(0));
+    let container = Container::new();
+    let counter = built.clone();
+    container
+        .bind::<Settings, _>(move |_| Arc::new(Settings(counter.fetch_add(1, Ordering::SeqCst))));
+
+    assert_eq!(container.resolve_optional::<Settings>().unwrap().0, 0);
+    assert!(container.forget_instance::<Settings>());
+    assert!(!container.forget_instance::<Settings>());
+    assert_eq!(container.resolve_optional::<Settings>().unwrap().0, 1);
+}
+
+#[test]
+fn forgotten_and_flushed_bindings_can_be_bound_again() {
+    let container = Container::new();
+    container.bind::<Settings, _>(|_| Arc::new(Settings(1)));
+    assert_eq!(container.resolve_optional::<Settings>().unwrap().0, 1);
+
+    assert!(container.forget_binding::<Settings>());
+    assert!(container.resolve_optional::<Settings>().is_none());
+    container.bind::<Settings, _>(|_| Arc::new(Settings(2)));
+    assert_eq!(container.resolve_optional::<Settings>().unwrap().0, 2);
+
+    container.flush();
+    assert!(container.resolve_optional::<Settings>().is_none());
+    container.bind::<Settings, _>(|_| Arc::new(Settings(3)));
+    assert_eq!(container.resolve_optional::<Settings>().unwrap().0, 3);
+}
+
+#[test]
+fn frozen_containers_cannot_be_flushed() {
+    let container = Container::new();
+    container.bind_transient::<Janitor, _>(|c| {
+        c.flush();
+        Arc::new(Janitor)
+    });
+    container.bind::<Settings, _>(|_| Arc::new(Settings(1)));
+
+    let frozen = container.freeze();
+    let payload = catch_unwind(AssertUnwindSafe(|| {
+        frozen.get::<Janitor>();
+    }))
+    .unwrap_err();
+    let message = payload
+        .downcast_ref::<String>()
+        .cloned()
+        .unwrap_or_else(|| {
+            payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .unwrap_or_default()
+        });
+    assert!(message.contains("container is frozen"), "{message}");
+    assert_eq!(frozen.get::<Settings>().unwrap().0, 1);
+}
@@ -0,0 +1,72 @@
+use luminos_container::{Container, Injectable};
+use luminos_contracts::container::Contract;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct Tenant(&'static str);
+
+impl Injectable for Tenant {
+    fn __register<C: Contract>(container: &C) {
+        container.bind::<Tenant, _>(|_| Arc::new(Tenant("default")));
+    }
+}
+
+#[test]
+fn frozen_singletons_still_fire_resolved_callbacks() {
+    let container = Container::new();
+    container.bind::<Tenant, _>(|_| Arc::new(Tenant("default")));
+    let fired = Arc::new(AtomicUsize::new(0));
+    let counter = fired.clone();
+    container.on_resolved(move |_, _, _| {
+        counter.fetch_add(1, Ordering::SeqCst);
+    });
+
+    let frozen = container.freeze();
+    frozen.resolve::<Tenant>();
+
+    assert_eq!(fired.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn frozen_singletons_are_shadowed_by_scope_locals() {
+    let container = Container::new();
+    container.bind::<Tenant, _>(|_| Arc::new(Tenant("default")));
+    let frozen = container.freeze();
+
+    let scope = frozen.scope();
+    scope.insert_local(Tenant("acme"));
+
+    assert_eq!(scope.enter(|_| frozen.resolve::<Tenant>()).0, "acme");
+    assert_eq!(frozen.resolve::<Tenant>().0, "default");
+}
+
+#[test]
+fn into_inner_unfreezes() {
+    let container = Container::new();
+    let container = container.freeze().into_inner();
+
+    assert!(!container.is_frozen());
+    container.bind::<Tenant, _>(|_| Arc::new(Tenant("rebound")));
+}
+
+#[derive(Clone, Copy)]
+struct Limit(u32);
+
+struct Reloader;
+
+#[test]
+fn frozen_containers_reject_value_bindings() {
+    let container = Container::new();
+    container.bind_value(Limit(10));
+    container.bind_transient::<Reloader, _>(|c| {
+        c.bind_value(Limit(20));
+        Arc::new(Reloader)
+    });
+
+    let frozen = container.freeze();
+    let rebound = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        frozen.get::<Reloader>();
+    }));
+    assert!(rebound.is_err());
+    assert_eq!(frozen.get_value::<Limit>().unwrap().0, 10);
+}
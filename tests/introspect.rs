@@ -0,0 +1,28 @@
+use luminos_container::Container;
+use luminos_contracts::container::Contract;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::sync::Arc;
+
+struct Explosive;
+
+impl Drop for Explosive {
+    fn drop(&mut self) {
+        panic!("explosive dropped");
+    }
+}
+
+#[test]
+fn debug_output_survives_a_poisoned_instance_store() {
+    let container = Container::new();
+    container.bind::<Explosive, _>(|_| Arc::new(Explosive));
+    drop(container.resolve_optional::<Explosive>());
+
+    // Dropping the last handle inside `clear` poisons the store's lock.
+    let cleared = catch_unwind(AssertUnwindSafe(|| {
+        container.forget_instances();
+    }));
+    assert!(cleared.is_err());
+
+    let debug = format!("{container:#?}");
+    assert!(debug.contains("Explosive"));
+}
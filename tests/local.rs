@@ -0,0 +1,65 @@
+use luminos_container::Container;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+struct Probe(Arc<AtomicUsize>);
+
+impl Drop for Probe {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+struct Other;
+
+fn container(dropped: &Arc<AtomicUsize>) -> Container {
+    let dropped = dropped.clone();
+    let container = Container::new();
+    container.bind_local::<Probe, _>(move |_| Probe(dropped.clone()));
+    container.bind_local::<Other, _>(|_| Other);
+    container
+}
+
+#[test]
+fn dropping_a_container_releases_this_threads_instances() {
+    let dropped = Arc::new(AtomicUsize::new(0));
+    let container = container(&dropped);
+
+    drop(container.resolve_local::<Probe>());
+    assert_eq!(dropped.load(Ordering::SeqCst), 0);
+
+    drop(container);
+    assert_eq!(dropped.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn worker_threads_prune_instances_of_dropped_containers() {
+    let dropped = Arc::new(AtomicUsize::new(0));
+    let first = Arc::new(container(&dropped));
+    let second = Arc::new(container(&dropped));
+
+    let (resolve, jobs) = mpsc::channel::<Arc<Container>>();
+    let (done, finished) = mpsc::channel();
+    let worker = thread::spawn(move || {
+        for container in jobs {
+            drop(container.resolve_local::<Other>());
+            drop(container.resolve_local::<Probe>());
+            drop(container);
+            done.send(()).unwrap();
+        }
+    });
+
+    resolve.send(first.clone()).unwrap();
+    finished.recv().unwrap();
+    drop(first);
+    assert_eq!(dropped.load(Ordering::SeqCst), 0);
+
+    resolve.send(second.clone()).unwrap();
+    finished.recv().unwrap();
+    assert_eq!(dropped.load(Ordering::SeqCst), 1);
+
+    drop(resolve);
+    worker.join().unwrap();
+}
@@ -0,0 +1,75 @@
+use luminos_container::{ClosureProvider, Container, ManifestConflict, ProviderManifest, Version};
+
+fn manifest(name: &'static str, contract: Version) -> ProviderManifest {
+    ProviderManifest::new(name, Version::new(1, 0, 0)).provides("cache", contract)
+}
+
+#[test]
+fn pre_1_0_minor_versions_conflict() {
+    let container = Container::new();
+    container
+        .add_provider_with_manifest(
+            Box::new(ClosureProvider::new(|_| {}, |_| {})),
+            manifest("redis", Version::new(0, 1, 0)),
+        )
+        .unwrap();
+
+    let conflicts = container
+        .add_provider_with_manifest(
+            Box::new(ClosureProvider::new(|_| {}, |_| {})),
+            manifest("memcached", Version::new(0, 2, 0)),
+        )
+        .unwrap_err();
+
+    assert!(matches!(
+        conflicts[..],
+        [ManifestConflict::IncompatibleContract {
+            contract: "cache",
+            ..
+        }]
+    ));
+}
+
+#[test]
+fn same_major_versions_are_compatible() {
+    let container = Container::new();
+    container
+        .add_provider_with_manifest(
+            Box::new(ClosureProvider::new(|_| {}, |_| {})),
+            manifest("redis", Version::new(1, 2, 0)),
+        )
+        .unwrap();
+
+    assert!(
+        container
+            .add_provider_with_manifest(
+                Box::new(ClosureProvider::new(|_| {}, |_| {})),
+                manifest("memcached", Version::new(1, 5, 0)),
+            )
+            .is_ok()
+    );
+}
+
+#[test]
+fn conflicts_name_where_each_provider_was_added() {
+    let container = Container::new();
+    container
+        .add_provider_with_manifest(
+            Box::new(ClosureProvider::new(|_| {}, |_| {})),
+            manifest("redis", Version::new(1, 0, 0)),
+        )
+        .unwrap();
+
+    let conflicts = container
+        .add_provider_with_manifest(
+            Box::new(ClosureProvider::new(|_| {}, |_| {})),
+            manifest("redis", Version::new(1, 0, 0)),
+        )
+        .unwrap_err();
+
+    let message = conflicts[0].to_string();
+    assert!(
+        message.contains(&format!("first added at {}", file!())),
+        "{message}"
+    );
+}
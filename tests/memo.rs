@@ -0,0 +1,33 @@
+use luminos_container::{Container, Injectable, Memoizer};
+use luminos_contracts::container::Contract;
+use std::sync::Arc;
+use std::time::Duration;
+
+struct Pricing {
+    memo: Arc<Memoizer>,
+}
+
+impl Injectable for Pricing {
+    fn __register<C: Contract>(container: &C) {
+        container.bind::<Pricing, _>(|c| {
+            Arc::new(Pricing {
+                memo: c.resolve::<Memoizer>(),
+            })
+        });
+    }
+}
+
+#[test]
+fn injected_memoizer_is_the_container_wide_one() {
+    let container = Container::new();
+    let pricing = container.resolve::<Pricing>();
+
+    pricing
+        .memo
+        .remember("rate", Duration::from_secs(60), || 7u32);
+    let cached = container
+        .memoizer()
+        .remember("rate", Duration::from_secs(60), || 0u32);
+
+    assert_eq!(cached, 7);
+}
@@ -0,0 +1,13 @@
+use luminos_container::{Container, InMemoryMeter};
+
+#[test]
+fn set_meter_replaces_an_already_resolved_meter() {
+    let container = Container::new();
+    container.metrics().counter("requests", 1);
+
+    let meter = InMemoryMeter::new();
+    container.set_meter(meter.clone());
+    container.metrics().counter("requests", 1);
+
+    assert_eq!(meter.counter_value("requests"), 1);
+}
@@ -0,0 +1,41 @@
+use luminos_container::Container;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+
+#[derive(Debug)]
+struct Pool {
+    _fallback: Option<Arc<Pool>>,
+}
+
+#[test]
+fn cyclic_named_bindings_are_reported() {
+    let container = Container::new();
+    container.bind_named::<Pool, _>("read", |c| {
+        Arc::new(Pool {
+            _fallback: Some(c.resolve_named::<Pool>("write")),
+        })
+    });
+    container.bind_named::<Pool, _>("write", |c| {
+        Arc::new(Pool {
+            _fallback: Some(c.resolve_named::<Pool>("read")),
+        })
+    });
+
+    let err = panic::catch_unwind(AssertUnwindSafe(|| container.resolve_named::<Pool>("read")))
+        .unwrap_err();
+    let message = err.downcast_ref::<String>().unwrap();
+    assert!(message.starts_with("Circular dependency detected"));
+}
+
+#[test]
+fn named_bindings_may_depend_on_other_names_of_their_type() {
+    let container = Container::new();
+    container.bind_named::<Pool, _>("primary", |_| Arc::new(Pool { _fallback: None }));
+    container.bind_named::<Pool, _>("read", |c| {
+        Arc::new(Pool {
+            _fallback: Some(c.resolve_named::<Pool>("primary")),
+        })
+    });
+
+    container.resolve_named::<Pool>("read");
+}
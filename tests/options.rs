@@ -0,0 +1,61 @@
+use luminos_container::{Container, Injectable, Options};
+use luminos_contracts::container::Contract;
+use std::sync::Arc;
+
+#[derive(Debug, Default, PartialEq)]
+struct CorsOptions {
+    origins: Vec<&'static str>,
+    max_age: u32,
+}
+
+struct CorsMiddleware {
+    options: Arc<Options<CorsOptions>>,
+}
+
+impl Injectable for CorsMiddleware {
+    fn __register<C: Contract>(container: &C) {
+        container.bind::<CorsMiddleware, _>(|c| {
+            Arc::new(CorsMiddleware {
+                options: c.resolve::<Options<CorsOptions>>(),
+            })
+        });
+    }
+}
+
+#[test]
+fn options_are_resolvable_and_injectable() {
+    let container = Container::new();
+    assert_eq!(
+        **container.resolve::<Options<CorsOptions>>(),
+        CorsOptions::default()
+    );
+
+    container.configure_options::<CorsOptions, _>(|o| o.origins.push("https://example.com"));
+    container.configure_options::<CorsOptions, _>(|o| o.max_age = 600);
+
+    let middleware = container.resolve::<CorsMiddleware>();
+    assert_eq!(middleware.options.origins, ["https://example.com"]);
+    assert_eq!(middleware.options.max_age, 600);
+}
+
+#[test]
+fn resolving_options_runs_their_validators() {
+    let container = Container::new();
+    container.validate_options_with::<CorsOptions, _>(|o| {
+        if o.origins.is_empty() {
+            Err("at least one origin is required".to_string())
+        } else {
+            Ok(())
+        }
+    });
+
+    let error = container.try_resolve::<Options<CorsOptions>>().unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("at least one origin is required")
+    );
+
+    container.configure_options::<CorsOptions, _>(|o| o.origins.push("https://example.com"));
+    assert!(container.try_resolve::<Options<CorsOptions>>().is_ok());
+}
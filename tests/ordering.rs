@@ -0,0 +1,54 @@
+use luminos_container::{ClosureProvider, Container, OrderError};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+fn noop() -> Box<ClosureProvider> {
+    Box::new(ClosureProvider::new(|_| {}, |_| {}))
+}
+
+#[test]
+fn providers_run_after_their_dependencies() {
+    let container = Container::new();
+    container
+        .add_provider_after("http", &["cache", "database"], noop())
+        .add_provider_after("cache", &["database"], noop())
+        .add_provider_after("database", &[], noop());
+
+    assert_eq!(
+        container.provider_order().unwrap(),
+        vec!["database", "cache", "http"]
+    );
+}
+
+#[test]
+fn cycles_and_unknown_names_are_order_errors() {
+    let cyclic = Container::new();
+    cyclic
+        .add_provider_after("queue", &["mail"], noop())
+        .add_provider_after("mail", &["queue"], noop());
+    assert_eq!(
+        cyclic.provider_order().unwrap_err(),
+        OrderError::Cycle(vec!["queue", "mail", "queue"])
+    );
+
+    let unknown = Container::new();
+    unknown.add_provider_after("http", &["session"], noop());
+    assert_eq!(
+        unknown.provider_order().unwrap_err(),
+        OrderError::MissingDependency {
+            node: "http",
+            dependency: "session",
+        }
+    );
+}
+
+#[test]
+fn anonymous_providers_cannot_be_ordered_against() {
+    let container = Container::new();
+    container.provide(|_| {}, |_| {});
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        container.add_provider_after("http", &[""], noop());
+    }));
+    assert!(result.is_err());
+    assert_eq!(container.provider_order().unwrap(), vec!["<anonymous>"]);
+}
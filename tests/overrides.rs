@@ -0,0 +1,42 @@
+use luminos_container::Container;
+use luminos_contracts::container::Contract;
+use std::sync::Arc;
+
+struct Pool(&'static str);
+
+struct Handler(&'static str);
+
+#[derive(Clone, Copy)]
+struct Limit(u32);
+
+#[test]
+fn dropping_the_guard_rolls_back_every_binding_kind() {
+    let container = Container::new();
+    container.bind::<Pool, _>(|_| Arc::new(Pool("primary")));
+    container.bind_named::<Pool, _>("read", |_| Arc::new(Pool("replica")));
+    container.bind_many::<Handler, _>(|_| Arc::new(Handler("audit")));
+    container.bind_value(Limit(10));
+
+    {
+        let _guard = container.push_overrides();
+        container.bind_named::<Pool, _>("read", |_| Arc::new(Pool("fake")));
+        container.bind_named::<Pool, _>("write", |_| Arc::new(Pool("fake")));
+        container.bind_many::<Handler, _>(|_| Arc::new(Handler("fake")));
+        container.bind_value(Limit(1));
+        container.tag::<Pool>("fakes");
+        container.decorate::<Pool, _>(|_, _| Arc::new(Pool("decorated")));
+
+        assert_eq!(container.resolve_named::<Pool>("read").0, "fake");
+        assert_eq!(container.resolve_all::<Handler>().len(), 2);
+        assert_eq!(container.get_value::<Limit>().unwrap().0, 1);
+        assert_eq!(container.tagged("fakes").len(), 1);
+        assert_eq!(container.resolve_optional::<Pool>().unwrap().0, "decorated");
+    }
+
+    assert_eq!(container.resolve_named::<Pool>("read").0, "replica");
+    assert!(container.get_named::<Pool>("write").is_none());
+    assert_eq!(container.resolve_all::<Handler>().len(), 1);
+    assert_eq!(container.get_value::<Limit>().unwrap().0, 10);
+    assert!(container.tagged("fakes").is_empty());
+    assert_eq!(container.resolve_optional::<Pool>().unwrap().0, "primary");
+}
@@ -0,0 +1,51 @@
+use luminos_container::{Container, ProfileEntry, ResolutionProfile};
+use luminos_contracts::container::Contract;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+struct Cache;
+
+struct RequestContext;
+
+struct Clock;
+
+fn entry<T>(micros: u64) -> ProfileEntry {
+    ProfileEntry {
+        type_name: std::any::type_name::<T>().to_string(),
+        duration: Duration::from_micros(micros),
+    }
+}
+
+#[test]
+fn prewarm_builds_each_profiled_singleton_once() {
+    let built = Arc::new(AtomicUsize::new(0));
+    let transient_built = Arc::new(AtomicUsize::new(0));
+    let container = Container::new();
+
+    let counter = built.clone();
+    container.bind::<Cache, _>(move |_| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Arc::new(Cache)
+    });
+    container.bind_scoped::<RequestContext, _>(|_| Arc::new(RequestContext));
+    let counter = transient_built.clone();
+    container.bind_transient::<Clock, _>(move |_| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Arc::new(Clock)
+    });
+
+    let profile = ResolutionProfile {
+        entries: vec![
+            entry::<Cache>(30),
+            entry::<RequestContext>(20),
+            entry::<Clock>(10),
+            entry::<Cache>(5),
+        ],
+    };
+    container.prewarm(&profile, 4);
+
+    assert_eq!(built.load(Ordering::SeqCst), 1);
+    assert_eq!(transient_built.load(Ordering::SeqCst), 0);
+    assert!(container.is_resolved::<Cache>());
+}
@@ -0,0 +1,35 @@
+use luminos_container::Container;
+use luminos_contracts::container::Contract;
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::task::{Context, Poll, Wake, Waker};
+
+struct Plugin;
+
+#[derive(Default)]
+struct CountingWaker(AtomicUsize);
+
+impl Wake for CountingWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn repeated_polls_register_one_waker() {
+    let container = Container::new();
+    let counter = Arc::new(CountingWaker::default());
+    let waker = Waker::from(counter.clone());
+    let mut cx = Context::from_waker(&waker);
+
+    let mut future = pin!(container.resolve_when_available::<Plugin>());
+    for _ in 0..3 {
+        assert!(future.as_mut().poll(&mut cx).is_pending());
+    }
+
+    container.bind::<Plugin, _>(|_| Arc::new(Plugin));
+    assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+    assert!(matches!(future.as_mut().poll(&mut cx), Poll::Ready(_)));
+}
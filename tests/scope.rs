@@ -0,0 +1,56 @@
+use luminos_container::{Container, Injectable};
+use luminos_contracts::container::Contract;
+use std::sync::Arc;
+
+struct Tenant(&'static str);
+
+impl Injectable for Tenant {
+    fn __register<C: Contract>(container: &C) {
+        container.bind::<Tenant, _>(|_| Arc::new(Tenant("default")));
+    }
+}
+
+struct Greeter {
+    tenant: Arc<Tenant>,
+}
+
+impl Injectable for Greeter {
+    fn __register<C: Contract>(container: &C) {
+        container.bind::<Greeter, _>(|c| {
+            Arc::new(Greeter {
+                tenant: c.resolve::<Tenant>(),
+            })
+        });
+    }
+}
+
+#[test]
+fn locals_shadow_transitive_dependencies() {
+    let container = Container::new();
+    container.bind_transient::<Greeter, _>(|c| {
+        Arc::new(Greeter {
+            tenant: c.resolve::<Tenant>(),
+        })
+    });
+
+    let scope = container.scope();
+    scope.insert_local(Tenant("acme"));
+
+    assert_eq!(scope.resolve::<Greeter>().tenant.0, "acme");
+    assert_eq!(container.resolve::<Greeter>().tenant.0, "default");
+}
+
+#[test]
+fn singletons_first_resolved_in_a_scope_do_not_capture_its_locals() {
+    let container = Container::new();
+
+    let first = container.scope();
+    first.insert_local(Tenant("acme"));
+    assert_eq!(first.resolve::<Greeter>().tenant.0, "default");
+    drop(first);
+
+    let second = container.scope();
+    second.insert_local(Tenant("globex"));
+    assert_eq!(second.resolve::<Greeter>().tenant.0, "default");
+    assert_eq!(container.resolve::<Greeter>().tenant.0, "default");
+}
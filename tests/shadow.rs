@@ -0,0 +1,64 @@
+use luminos_container::Container;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+trait Pricing: Send + Sync {
+    fn price(&self, quantity: u32) -> u32;
+}
+
+struct Flat;
+
+impl Pricing for Flat {
+    fn price(&self, quantity: u32) -> u32 {
+        quantity * 10
+    }
+}
+
+struct Bulk;
+
+impl Pricing for Bulk {
+    fn price(&self, quantity: u32) -> u32 {
+        quantity * 9
+    }
+}
+
+struct Counted {
+    inner: Arc<dyn Pricing>,
+    calls: Arc<AtomicUsize>,
+}
+
+impl Pricing for Counted {
+    fn price(&self, quantity: u32) -> u32 {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        self.inner.price(quantity)
+    }
+}
+
+#[test]
+fn both_implementations_go_through_decorators() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let container = Container::new();
+    container.bind_versioned::<dyn Pricing, _>("v1", |_| Arc::new(Flat));
+    container.bind_versioned::<dyn Pricing, _>("v2", |_| Arc::new(Bulk));
+    let counter = calls.clone();
+    container.decorate::<Arc<dyn Pricing>, _>(move |inner, _| {
+        Arc::new(Arc::new(Counted {
+            inner: (*inner).clone(),
+            calls: counter.clone(),
+        }) as Arc<dyn Pricing>)
+    });
+
+    let mismatches = Arc::new(Mutex::new(Vec::new()));
+    let reported = mismatches.clone();
+    let shadow = container
+        .resolve_shadow::<dyn Pricing>("v1", "v2")
+        .on_mismatch(move |mismatch| reported.lock().unwrap().push(mismatch.clone()));
+
+    assert_eq!(shadow.call(|pricing| pricing.price(3)), 30);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    let mismatches = mismatches.lock().unwrap();
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(mismatches[0].primary, "30");
+    assert_eq!(mismatches[0].shadow.as_deref(), Some("27"));
+}
@@ -0,0 +1,57 @@
+use luminos_container::{Container, ContainerError, PersistentState, StateStore};
+use luminos_contracts::container::Contract;
+use std::io;
+use std::sync::Arc;
+
+struct Counter;
+
+impl PersistentState for Counter {
+    const KEY: &'static str = "counter";
+
+    fn save(&self) -> String {
+        "1".to_string()
+    }
+
+    fn restore(&self, _state: &str) {}
+}
+
+struct ReadOnlyStore;
+
+impl StateStore for ReadOnlyStore {
+    fn load(&self, _key: &str) -> Option<String> {
+        None
+    }
+
+    fn store(&self, _key: &str, _state: &str) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::PermissionDenied, "read-only"))
+    }
+}
+
+#[test]
+fn failing_to_persist_returns_an_error_and_keeps_instances() {
+    let container = Container::new();
+    container.bind::<Counter, _>(|_| Arc::new(Counter));
+    container
+        .set_state_store(ReadOnlyStore)
+        .persist::<Counter>();
+    let counter = container.resolve_optional::<Counter>().unwrap();
+
+    let err = container.shutdown().unwrap_err();
+    assert!(matches!(err, ContainerError::PersistFailed { .. }));
+    assert!(err.type_name().ends_with("Counter"));
+    assert!(Arc::ptr_eq(
+        &counter,
+        &container.resolve_optional::<Counter>().unwrap()
+    ));
+}
+
+#[test]
+fn the_shutdown_signal_stays_triggered() {
+    let container = Container::new();
+    container.bind::<Counter, _>(|_| Arc::new(Counter));
+    container.resolve_optional::<Counter>();
+
+    container.shutdown().unwrap();
+
+    assert!(container.shutdown_signal().is_triggered());
+}
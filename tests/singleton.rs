@@ -0,0 +1,38 @@
+use luminos_container::Container;
+use luminos_contracts::container::Contract;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Barrier};
+use std::thread;
+use std::time::Duration;
+
+struct Connection;
+
+#[test]
+fn racing_first_resolves_build_the_singleton_once() {
+    const THREADS: usize = 8;
+
+    let built = Arc::new(AtomicUsize::new(0));
+    let container = Container::new();
+    let counter = built.clone();
+    container.bind::<Connection, _>(move |_| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        thread::sleep(Duration::from_millis(20));
+        Arc::new(Connection)
+    });
+
+    let barrier = Barrier::new(THREADS);
+    let resolved: Vec<_> = thread::scope(|s| {
+        let handles: Vec<_> = (0..THREADS)
+            .map(|_| {
+                s.spawn(|| {
+                    barrier.wait();
+                    container.resolve_optional::<Connection>().unwrap()
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+
+    assert_eq!(built.load(Ordering::SeqCst), 1);
+    assert!(resolved.iter().all(|c| Arc::ptr_eq(c, &resolved[0])));
+}
@@ -0,0 +1,60 @@
+use luminos_container::{Container, Injectable, Scope, StateMachine, Transition};
+use luminos_contracts::container::Contract;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Order {
+    Placed,
+    Paid,
+    Shipped,
+}
+
+struct Audit {
+    from: Order,
+}
+
+struct Advance {
+    audit: Arc<Audit>,
+}
+
+impl Injectable for Advance {
+    fn __register<C: Contract>(_container: &C) {}
+}
+
+impl Transition<Order> for Advance {
+    fn apply(&self, from: &Order, _scope: &Scope<'_>) -> Order {
+        assert_eq!(&self.audit.from, from);
+        match from {
+            Order::Placed => Order::Paid,
+            _ => Order::Shipped,
+        }
+    }
+}
+
+#[test]
+fn handlers_are_built_per_transition_and_see_the_state() {
+    let built = Arc::new(AtomicUsize::new(0));
+    let container = Container::new();
+    container.bind_transient::<Audit, _>(|c| {
+        Arc::new(Audit {
+            from: (*c.resolve_optional::<Order>().unwrap()).clone(),
+        })
+    });
+    let counter = built.clone();
+    container.bind::<Advance, _>(move |c| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Arc::new(Advance {
+            audit: c.resolve_optional::<Audit>().unwrap(),
+        })
+    });
+
+    let machine = StateMachine::new(Order::Placed).allow::<Advance>(&[Order::Placed, Order::Paid]);
+
+    assert_eq!(machine.transition::<Advance>(&container), Ok(Order::Paid));
+    assert_eq!(
+        machine.transition::<Advance>(&container),
+        Ok(Order::Shipped)
+    );
+    assert_eq!(built.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,45 @@
+#![cfg(any(feature = "tracing", feature = "metrics"))]
+
+use luminos_container::{Container, InMemoryMeter};
+use luminos_contracts::container::Contract;
+use std::sync::Arc;
+
+struct Cache;
+
+struct Clock;
+
+#[test]
+fn sampled_resolutions_are_recorded_to_the_meter() {
+    let meter = InMemoryMeter::new();
+    let container = Container::new();
+    container.set_meter(meter.clone());
+    container.bind::<Cache, _>(|_| Arc::new(Cache));
+
+    container.resolve_optional::<Cache>().unwrap();
+    container.resolve_optional::<Cache>().unwrap();
+    container.resolve_optional::<Cache>().unwrap();
+
+    assert_eq!(meter.counter_value("luminos_container.builds"), 1);
+    assert_eq!(meter.counter_value("luminos_container.cache_hits"), 2);
+    assert_eq!(
+        meter
+            .histogram_values("luminos_container.build_seconds")
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn unsampled_types_are_not_recorded() {
+    let meter = InMemoryMeter::new();
+    let container = Container::new();
+    container.set_meter(meter.clone());
+    container.set_sampling::<Clock>(0.0);
+    container.bind::<Clock, _>(|_| Arc::new(Clock));
+
+    container.resolve_optional::<Clock>().unwrap();
+    container.resolve_optional::<Clock>().unwrap();
+
+    assert_eq!(meter.counter_value("luminos_container.builds"), 0);
+    assert_eq!(meter.counter_value("luminos_container.cache_hits"), 0);
+}
@@ -0,0 +1,94 @@
+use luminos_container::{BootPhase, Container, FallibleServiceProvider, ProviderError};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct Failing {
+    register: Option<&'static str>,
+    boot: Option<&'static str>,
+    booted: Arc<AtomicUsize>,
+}
+
+impl Failing {
+    fn new(booted: &Arc<AtomicUsize>) -> Self {
+        Self {
+            register: None,
+            boot: None,
+            booted: booted.clone(),
+        }
+    }
+}
+
+impl FallibleServiceProvider for Failing {
+    fn register(&self, _container: &Container) -> Result<(), ProviderError> {
+        match self.register {
+            Some(message) => Err(message.into()),
+            None => Ok(()),
+        }
+    }
+
+    fn boot(&self, _container: &Container) -> Result<(), ProviderError> {
+        self.booted.fetch_add(1, Ordering::SeqCst);
+        match self.boot {
+            Some(message) => Err(message.into()),
+            None => Ok(()),
+        }
+    }
+}
+
+#[test]
+fn every_failing_boot_is_reported() {
+    let booted = Arc::new(AtomicUsize::new(0));
+    let container = Container::new();
+    container
+        .add_fallible_provider(Failing {
+            boot: Some("queue unreachable"),
+            ..Failing::new(&booted)
+        })
+        .add_fallible_provider(Failing::new(&booted))
+        .add_fallible_provider(Failing {
+            boot: Some("mail unreachable"),
+            ..Failing::new(&booted)
+        });
+
+    let report = container.try_boot().unwrap_err();
+    let failures: Vec<_> = report
+        .failures
+        .iter()
+        .map(|failure| (failure.phase, failure.message.as_str()))
+        .collect();
+    assert_eq!(
+        failures,
+        vec![
+            (BootPhase::Boot, "queue unreachable"),
+            (BootPhase::Boot, "mail unreachable"),
+        ]
+    );
+    assert_eq!(booted.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn nothing_boots_if_a_register_fails() {
+    let booted = Arc::new(AtomicUsize::new(0));
+    let container = Container::new();
+    container
+        .add_fallible_provider(Failing::new(&booted))
+        .add_fallible_provider(Failing {
+            register: Some("missing DATABASE_URL"),
+            ..Failing::new(&booted)
+        })
+        .add_fallible_provider(Failing {
+            register: Some("missing REDIS_URL"),
+            ..Failing::new(&booted)
+        });
+
+    let report = container.try_boot().unwrap_err();
+    assert_eq!(report.failures.len(), 2);
+    assert!(
+        report
+            .failures
+            .iter()
+            .all(|failure| failure.phase == BootPhase::Register)
+    );
+    assert_eq!(report.failures[1].message, "missing REDIS_URL");
+    assert_eq!(booted.load(Ordering::SeqCst), 0);
+}
@@ -0,0 +1,19 @@
+use luminos_container::{Container, ContainerError, TryContract};
+use luminos_contracts::container::Contract;
+use std::sync::Arc;
+
+struct Config;
+
+#[test]
+fn sealed_error_names_where_the_binding_was_made() {
+    let container = Container::new();
+    container.bind_sealed::<Config, _>(|_| Arc::new(Config));
+    let bound_at = container.binding_location::<Config>();
+
+    let error = container
+        .try_bind::<Config, _>(|_| Arc::new(Config))
+        .unwrap_err();
+
+    assert!(matches!(error, ContainerError::Sealed { bound_at: at, .. } if at == bound_at));
+    assert!(error.to_string().contains(&format!("bound at {}", file!())));
+}
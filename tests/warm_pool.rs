@@ -0,0 +1,62 @@
+use luminos_container::Container;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+struct Widget(u32);
+
+fn wait_for_pool(container: &Container, size: usize) {
+    let until = Instant::now() + Duration::from_secs(5);
+    while container.warm_pool_len::<Widget>() < size {
+        assert!(Instant::now() < until, "warm pool never filled");
+        thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn rebinding_discards_instances_built_by_the_old_factory() {
+    let container = Arc::new(Container::new());
+    container.bind_transient::<Widget, _>(|_| Arc::new(Widget(1)));
+    container.warm_pool::<Widget>(2);
+    wait_for_pool(&container, 2);
+
+    container.bind_transient::<Widget, _>(|_| Arc::new(Widget(2)));
+    assert_eq!(container.resolve_optional::<Widget>().unwrap().0, 2);
+
+    wait_for_pool(&container, 2);
+    assert_eq!(container.resolve_optional::<Widget>().unwrap().0, 2);
+}
+
+#[test]
+fn dropping_an_override_discards_instances_built_while_it_was_active() {
+    let container = Arc::new(Container::new());
+    container.bind_transient::<Widget, _>(|_| Arc::new(Widget(1)));
+    container.warm_pool::<Widget>(2);
+
+    let guard = container.push_overrides();
+    container.bind_transient::<Widget, _>(|_| Arc::new(Widget(2)));
+    wait_for_pool(&container, 2);
+    assert_eq!(container.resolve_optional::<Widget>().unwrap().0, 2);
+    drop(guard);
+
+    assert_eq!(container.resolve_optional::<Widget>().unwrap().0, 1);
+}
+
+#[test]
+fn scoped_resolves_bypass_the_pool() {
+    let built = Arc::new(AtomicU32::new(0));
+    let container = Arc::new(Container::new());
+    let counter = built.clone();
+    container.bind_transient::<Widget, _>(move |_| {
+        Arc::new(Widget(counter.fetch_add(1, Ordering::SeqCst)))
+    });
+    container.warm_pool::<Widget>(2);
+    wait_for_pool(&container, 2);
+
+    let scoped = container
+        .scope()
+        .enter(|_| container.resolve_optional::<Widget>().unwrap());
+    assert_eq!(scoped.0, 2);
+    assert_eq!(container.resolve_optional::<Widget>().unwrap().0, 0);
+}